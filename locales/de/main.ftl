@@ -0,0 +1,273 @@
+language-name = Deutsch
+
+## Allgemein
+
+no-quiz-running = Es läuft gerade kein Quiz!
+option-out-of-range = `{ $option }` muss zwischen { $min } und { $max } liegen!
+option-invalid-choice = `{ $value }` ist keine gültige Auswahl für `{ $option }`!
+voice-channel-required = Du musst in einem Sprachkanal sein, um diesen Befehl zu nutzen
+voice-reconnecting = Die Sprachverbindung ist abgebrochen, das Quiz pausiert, während ich mich neu verbinde...
+voice-reconnected = Wieder verbunden, das Quiz geht weiter!
+voice-reconnect-failed = Die Verbindung konnte nicht wiederhergestellt werden, das Quiz wird beendet.
+
+## /quiz
+
+quiz-description = Starte ein Musikquiz in deinem Sprachkanal
+quiz-option-quiz_length = Anzahl der Runden
+quiz-option-playlist = Playlist, die gespielt wird. Ohne Angabe wird sie aus einem Menü gewählt
+quiz-option-mode = Voreinstellung für Rundendauer, Punktevergabe und Hinweise
+quiz-option-round_duration = Sekunden zum Erraten jedes Songs
+quiz-option-scoring = Wie viele Punkte ein gefundener Teil wert ist
+quiz-option-answers = Welche Teile des Songs erraten werden müssen
+quiz-option-hints = Hinweise, die während jeder Runde Buchstaben aufdecken
+quiz-option-lobby_duration = Sekunden, um dem Quiz beizutreten
+quiz-option-min_players = Spieler, die zum Starten nötig sind
+quiz-option-late_join = Ob Spieler während des laufenden Quiz beitreten können
+quiz-option-random_start = Jeden Song an einer zufälligen Stelle starten
+quiz-option-distortion = Verzerrt die Songs für Extrapunkte
+quiz-option-sound_effects = Spielt einen Countdown, einen Gong für richtige Antworten und einen Summer
+quiz-mode-classic = Klassisch
+quiz-mode-casual = Entspannt (lange Runden mit Hinweisen)
+quiz-mode-lightning = Blitz (kurze Runden, schnelle Antworten zählen mehr)
+quiz-mode-hardcore = Hardcore (kurze Runden, zufälliger Start)
+quiz-scoring-fixed = Fest
+quiz-scoring-speed = Tempo (schnellere Antworten zählen mehr)
+quiz-answers-both = Interpret und Titel
+quiz-answers-artist = Nur Interpret
+quiz-answers-title = Nur Titel
+quiz-late-join-zero = Mit 0 Punkten starten
+quiz-late-join-catch_up = Zum letzten Platz aufschließen
+quiz-late-join-off = Aus
+quiz-distortion-off = Aus
+quiz-distortion-reversed = Rückwärts (3x Punkte)
+quiz-distortion-pitch_up = Höher (2x Punkte)
+quiz-distortion-pitch_down = Tiefer (2x Punkte)
+quiz-distortion-sped_up = Schneller (2x Punkte)
+quiz-distortion-muffled = Gedämpft (2x Punkte)
+quiz-distortion-random = Zufällig
+quiz-length-missing = Die Anzahl der Runden ist keine Zahl!
+quiz-results = Das Quiz ist vorbei! Hier sind die Ergebnisse:
+quiz-spectator-results = Zuschauer, nur zum Spaß:
+songs-read-failed = Die Songs konnten nicht aus der Datenbank geladen werden!
+songs-exhausted = In dieser Playlist gibt es keine abspielbaren Songs mehr!
+song-unplayable = **{ $title }** von **{ $artist }** konnte nicht abgespielt werden ({ $error }), ein anderer Song wird gewählt.
+jingle-join-failed = Der Beitritts-Jingle konnte nicht abgespielt werden ({ $error })
+jingle-leave-failed = Der Abschieds-Jingle konnte nicht abgespielt werden ({ $error })
+
+distortion-reversed = Rückwärts
+distortion-pitch_up = Höher
+distortion-pitch_down = Tiefer
+distortion-sped_up = Schneller
+distortion-muffled = Gedämpft
+
+## Playlist-Auswahl
+
+playlist-placeholder = Wähle eine Playlist
+playlist-add-new = Neue hinzufügen
+playlist-select = Bitte wähle eine Playlist!
+playlist-select-page = Bitte wähle eine Playlist! (Seite { $page }/{ $pages })
+playlist-read-failed = Die Playlists konnten nicht aus der Datenbank gelesen werden!
+playlist-timed-out = Zeit abgelaufen
+playlist-modal-title = Neue Playlist hinzufügen
+playlist-modal-content = Bitte gib eine Spotify-Playlist-URL ein
+playlist-modal-placeholder = Spotify-Playlist-URL eingeben
+playlist-modal-label = Playlist-URL
+playlist-modal-timed-out = Du hast zu lange gebraucht, um eine Playlist zu wählen
+playlist-invalid-url = Bitte gib eine gültige Spotify-Playlist-URL an
+playlist-adding = Versuche "{ $url }" hinzuzufügen
+playlist-chosen =
+    { $user } hat gewählt:
+    { $url }
+
+## Lobby
+
+lobby-title = Tritt dem Quiz bei!
+lobby-participants = Teilnehmer
+lobby-min-players = Mindestens { $count } Spieler nötig
+lobby-join = ✅ Beitreten
+lobby-leave = ❌ Verlassen
+lobby-ready = 👍 Bereit
+lobby-start = ▶️ Jetzt starten
+lobby-kick = Spieler entfernen (nur Host)
+lobby-starting = Das Quiz startet!
+lobby-cancelled = Es sind nicht genug Spieler beigetreten, das Quiz wurde abgebrochen.
+lobby-join-first = Tritt dem Quiz bei, bevor du dich bereit meldest!
+lobby-start-host-only = Nur der Host kann das Quiz starten!
+lobby-not-enough-players = Es sind noch nicht genug Spieler zum Starten da!
+lobby-kick-host-only = Nur der Host kann Spieler entfernen!
+lobby-countdown = Du hast { $seconds } Sekunden zum Beitreten!
+late-join-already-playing = Du spielst bereits mit!
+late-join-disabled = Späteres Beitreten ist in diesem Quiz deaktiviert.
+late-join-joined = Du bist dem Quiz beigetreten! Deine Antworten zählen ab der nächsten Runde.
+late-join-spectating = Du schaust jetzt zu. Deine Antworten werden nur zum Spaß gezählt.
+
+## Runden
+
+round-number = Runde { $round }
+round-distorted = Runde { $round } - { $distortion } ({ $points }x Punkte)
+status-starting = Startet...
+status-running = Noch { $seconds }s
+status-paused = ⏸️ Pausiert, noch { $seconds }s
+status-over = Runde vorbei
+status-title = Titel:
+status-artist = Interpret:
+status-standings = Punktestand
+status-join-late = 🙋 Nachträglich beitreten
+status-spectate = 👀 Zuschauen
+song-artist = Interpret
+song-album = Album
+song-year = Jahr
+song-title-found-by = Titel gefunden von
+song-artist-found-by = Interpret gefunden von
+guess-skipped = Übersprungen
+guess-nobody = Niemand
+guess-found = { $user } in { $seconds }s
+podium-points = { $points } Pkt.
+
+## /pause und /resume
+
+pause-description = Pausiert das laufende Quiz
+resume-description = Setzt das pausierte Quiz fort
+pause-host-only = Nur der Host des Quiz kann es pausieren!
+resume-host-only = Nur der Host des Quiz kann es fortsetzen!
+pause-already-paused = Das Quiz ist bereits pausiert!
+resume-not-paused = Das Quiz ist nicht pausiert!
+pause-done = Quiz pausiert, mit /resume geht es weiter.
+resume-done = Quiz fortgesetzt!
+
+## /skip
+
+skip-description = Überspringt den aktuellen Song
+skip-count = Stimmen zum Überspringen: { $count }
+skip-skipping = Wird übersprungen!
+
+## /volume
+
+volume-description = Ändert die Lautstärke des laufenden Quiz
+volume-option-level = Lautstärke in Prozent
+volume-host-only = Nur der Host des Quiz kann die Lautstärke ändern!
+volume-set = Lautstärke auf { $level }% gesetzt
+
+## /score
+
+score-description = Zeigt die Trivia-Bestenliste
+score-title = Trivia-Bestenliste
+score-option-period = Nur Spiele aus diesem Zeitraum zählen
+score-option-ranking = Wonach die Spieler sortiert werden
+score-option-playlist = Nur Spiele mit dieser Playlist zählen
+score-option-min_games = Spieler mit weniger Spielen auslassen
+score-option-season = Endstand einer vergangenen Saison anzeigen
+score-period-today = Heute
+score-period-week = Letzte 7 Tage
+score-period-month = Letzte 30 Tage
+score-period-season = Diese Saison
+score-period-all = Gesamt
+score-ranking-total = Punkte insgesamt
+score-ranking-average = Punkte pro Spiel
+score-ranking-wins = Gewonnene Spiele
+score-ranking-accuracy = Gefundene Teile
+score-summary = { $ranking }, { $period }
+score-playlist = Playlist: { $name }
+score-unknown-playlist = Es gibt keine Playlist namens "{ $name }"!
+score-empty = Es hat noch niemand ein passendes Spiel gespielt.
+score-season-current = Saison { $season }
+score-season-archived = Endstand der Saison { $season }
+score-unknown-season = Saison { $season } hat noch nicht begonnen!
+score-archived-options = Vergangene Saisons speichern nur Punkte, Spiele und Siege der Spieler, daher können sie weder nach Playlist gefiltert noch nach gefundenen Teilen sortiert werden.
+
+## /season
+
+season-description = Verwaltet die Bestenlisten-Saisons dieses Servers
+season-show-description = Zeigt die aktuelle Saison
+season-start-description = Archiviert den aktuellen Stand und startet eine neue Saison
+season-current = Dies ist Saison **{ $season }**, sie läuft seit { $since } (UTC).
+season-first = Dies ist die erste Saison, sie enthält alle Spiele auf diesem Server.
+season-started = Saison **{ $season }** hat begonnen! Der Endstand der Saison { $previous } ist archiviert, mit `/score season:{ $previous }` kannst du ihn ansehen.
+season-failed = Die Saison konnte nicht gelesen oder gestartet werden!
+
+## Song-Meldungen
+
+report-button = 🚩 Song melden
+report-done = Danke, der Song wurde gemeldet!
+report-duplicate = Du hast diesen Song bereits gemeldet.
+report-failed = Das Melden des Songs ist fehlgeschlagen!
+
+## /jingle
+
+jingle-description = Legt die Sounds fest, die beim Beitreten und Verlassen des Bots spielen
+jingle-set-description = Eigenen Jingle verwenden
+jingle-option-source = URL oder Dateiname im assets-Ordner
+jingle-reset-description = Zurück zum mitgelieferten Jingle
+jingle-enabled-description = Jingles ein- oder ausschalten
+jingle-option-enabled = Jingles beim Beitreten und Verlassen abspielen
+jingle-show-description = Zeigt die aktuellen Jingles
+jingle-option-event = Wann der Jingle gespielt wird
+jingle-event-join = Beitreten
+jingle-event-leave = Verlassen
+jingle-show =
+    Jingles aktiviert: **{ $enabled ->
+        [true] ja
+       *[false] nein
+    }**
+    Beitreten: `{ $join }`
+    Verlassen: `{ $leave }`
+jingle-invalid-source = `{ $source }` ist weder eine URL noch eine Datei im assets-Ordner, oder länger als { $seconds } Sekunden
+jingle-save-failed = Die Jingle-Einstellungen konnten nicht gespeichert werden!
+
+## /config
+
+config-description = Legt die Quiz-Standardwerte dieses Servers fest
+config-show-description = Zeigt die aktuellen Standardwerte
+config-set-description = Ändert einen oder mehrere Standardwerte
+config-reset-description = Zurück zu den mitgelieferten Standardwerten
+config-option-lobby_duration = Sekunden, um einem Quiz beizutreten
+config-option-round_duration = Sekunden zum Erraten jedes Songs
+config-option-title_threshold = Erlaubte Tippfehler beim Titel
+config-option-artist_threshold = Erlaubte Tippfehler beim Interpreten
+config-option-skip_percentage = Anteil der Spieler in Prozent, der zum Überspringen nötig ist
+config-option-volume = Lautstärke in Prozent
+config-option-language = Sprache des Bots auf diesem Server
+config-option-share_playlists = Andere Server dürfen die hier hinzugefügten Playlists spielen
+config-show =
+    Lobby-Dauer: **{ $lobby_duration }s**
+    Rundendauer: **{ $round_duration }s**
+    Erlaubte Tippfehler beim Titel: **{ $title_threshold }**
+    Erlaubte Tippfehler beim Interpreten: **{ $artist_threshold }**
+    Stimmen zum Überspringen: **{ $skip_percentage }%**
+    Lautstärke: **{ $volume }%**
+    Sprache: **{ $language }**
+    Playlists mit anderen Servern geteilt: **{ $share_playlists ->
+        [true] ja
+       *[false] nein
+    }**
+config-invalid-language = `{ $code }` ist keine unterstützte Sprache!
+config-save-failed = Die Einstellungen konnten nicht gespeichert werden!
+
+## /stats
+
+stats-description = Zeigt die Quiz-Statistiken eines Spielers
+stats-option-user = Spieler, ohne Angabe du selbst
+stats-title = Statistiken von { $user }
+stats-games = Gespielte Spiele
+stats-wins = Siege
+stats-total-points = Punkte insgesamt
+stats-average-points = Punkte pro Spiel
+stats-title-accuracy = Gefundene Titel
+stats-artist-accuracy = Gefundene Interpreten
+stats-accuracy-value = { $percent }% ({ $found }/{ $asked })
+stats-reaction-time = Durchschnittliche Reaktionszeit
+stats-longest-streak = Längste Serie
+stats-streak-value = { $rounds ->
+    [one] { $rounds } Runde
+   *[other] { $rounds } Runden
+}
+stats-best-playlist = Beste Playlist
+stats-playlist-value = { $name } ({ $average } Punkte pro Spiel)
+stats-top-artists = Am häufigsten erkannte Interpreten
+stats-artist-value = { $artist } ({ $rounds ->
+    [one] { $rounds } Song
+   *[other] { $rounds } Songs
+})
+stats-none = -
+stats-no-games = { $user } hat auf diesem Server noch kein Quiz gespielt.
+stats-failed = Die Statistiken konnten nicht gelesen werden!
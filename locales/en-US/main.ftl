@@ -0,0 +1,267 @@
+language-name = English
+
+## Shared
+
+no-quiz-running = There is no quiz running!
+option-out-of-range = `{ $option }` has to be between { $min } and { $max }!
+option-invalid-choice = `{ $value }` is not a valid choice for `{ $option }`!
+voice-channel-required = You must be in a voice channel to use this command
+voice-reconnecting = Lost the voice connection, the quiz is paused while I try to reconnect...
+voice-reconnected = Reconnected, the quiz continues!
+voice-reconnect-failed = Could not reconnect, ending the quiz.
+
+## /quiz
+
+quiz-description = Start a music quiz in your voice channel
+quiz-option-quiz_length = Quiz length
+quiz-option-playlist = Playlist to play, pick one in a menu if left out
+quiz-option-mode = Preset for the round duration, scoring and hints
+quiz-option-round_duration = Seconds to guess each song
+quiz-option-scoring = How many points a found part is worth
+quiz-option-answers = Which parts of the song have to be guessed
+quiz-option-hints = Hints revealing letters during each round
+quiz-option-lobby_duration = Seconds to join the quiz
+quiz-option-min_players = Players needed to start the quiz
+quiz-option-late_join = Whether players can join while the quiz is running
+quiz-option-random_start = Start every song at a random position
+quiz-option-distortion = Distort the songs for extra points
+quiz-option-sound_effects = Play a countdown, a chime for correct guesses and a buzzer
+quiz-mode-classic = Classic
+quiz-mode-casual = Casual (long rounds with hints)
+quiz-mode-lightning = Lightning (short rounds, speed scoring)
+quiz-mode-hardcore = Hardcore (short rounds, random start)
+quiz-scoring-fixed = Fixed
+quiz-scoring-speed = Speed (faster guesses score more)
+quiz-answers-both = Artist and title
+quiz-answers-artist = Artist only
+quiz-answers-title = Title only
+quiz-late-join-zero = Start at 0 points
+quiz-late-join-catch_up = Catch up to the last place
+quiz-late-join-off = Off
+quiz-distortion-off = Off
+quiz-distortion-reversed = Reversed (3x points)
+quiz-distortion-pitch_up = Pitched up (2x points)
+quiz-distortion-pitch_down = Pitched down (2x points)
+quiz-distortion-sped_up = Sped up (2x points)
+quiz-distortion-muffled = Muffled (2x points)
+quiz-distortion-random = Random
+quiz-length-missing = Quiz length is not an integer!
+quiz-results = The Quiz is over! Here are the results:
+quiz-spectator-results = Spectators, just for fun:
+songs-read-failed = Failed to fetch Songs from DB!
+songs-exhausted = There are no more playable songs in this playlist!
+song-unplayable = Could not play **{ $title }** by **{ $artist }** ({ $error }), picking another song.
+jingle-join-failed = Could not play the join jingle ({ $error })
+jingle-leave-failed = Could not play the leave jingle ({ $error })
+
+distortion-reversed = Reversed
+distortion-pitch_up = Pitched up
+distortion-pitch_down = Pitched down
+distortion-sped_up = Sped up
+distortion-muffled = Muffled
+
+## Playlist selection
+
+playlist-placeholder = Select a playlist
+playlist-add-new = Add new
+playlist-select = Please select a playlist!
+playlist-select-page = Please select a playlist! (Page { $page }/{ $pages })
+playlist-read-failed = Reading Playlists from Database failed!
+playlist-timed-out = Timed out
+playlist-modal-title = Add a new Playlist
+playlist-modal-content = Please enter a Spotify-Playlist URL
+playlist-modal-placeholder = Enter a Spotify-Playlist URL
+playlist-modal-label = Playlist URL
+playlist-modal-timed-out = You took too long to select a playlist
+playlist-invalid-url = Please provide a valid Spotify-Playlist-Url
+playlist-adding = Trying to add "{ $url }"
+playlist-chosen =
+    { $user } chose:
+    { $url }
+
+## Lobby
+
+lobby-title = Join the quiz!
+lobby-participants = Participants
+lobby-min-players = At least { $count } players needed
+lobby-join = ✅ Join
+lobby-leave = ❌ Leave
+lobby-ready = 👍 Ready
+lobby-start = ▶️ Start now
+lobby-kick = Kick a player (host only)
+lobby-starting = The quiz is starting!
+lobby-cancelled = Not enough players joined, the quiz was cancelled.
+lobby-join-first = Join the quiz before getting ready!
+lobby-start-host-only = Only the host can start the quiz!
+lobby-not-enough-players = There are not enough players to start yet!
+lobby-kick-host-only = Only the host can kick players!
+lobby-countdown = You have { $seconds } seconds to join!
+late-join-already-playing = You are already playing!
+late-join-disabled = Joining late is disabled for this quiz.
+late-join-joined = You joined the quiz! Your guesses count from the next round on.
+late-join-spectating = You are spectating now. Your guesses are tracked just for fun.
+
+## Rounds
+
+round-number = Round { $round }
+round-distorted = Round { $round } - { $distortion } ({ $points }x points)
+status-starting = Starting...
+status-running = { $seconds }s left
+status-paused = ⏸️ Paused with { $seconds }s left
+status-over = Round over
+status-title = Title:
+status-artist = Artist:
+status-standings = Standings
+status-join-late = 🙋 Join late
+status-spectate = 👀 Spectate
+song-artist = Artist
+song-album = Album
+song-year = Year
+song-title-found-by = Title found by
+song-artist-found-by = Artist found by
+guess-skipped = Skipped
+guess-nobody = Nobody
+guess-found = { $user } in { $seconds }s
+podium-points = { $points } pts
+
+## /pause and /resume
+
+pause-description = Pause the running quiz
+resume-description = Resume the paused quiz
+pause-host-only = Only the host of the quiz can pause it!
+resume-host-only = Only the host of the quiz can resume it!
+pause-already-paused = The quiz is already paused!
+resume-not-paused = The quiz is not paused!
+pause-done = Quiz paused, use /resume to continue.
+resume-done = Quiz resumed!
+
+## /skip
+
+skip-description = Skip the current song
+skip-count = Skip count: { $count }
+skip-skipping = Skipping!
+
+## /volume
+
+volume-description = Change the volume of the running quiz
+volume-option-level = Volume in percent
+volume-host-only = Only the host of the quiz can change the volume!
+volume-set = Volume set to { $level }%
+
+## /score
+
+score-description = Display the Trivia-Scoreboard
+score-title = Trivia-Scoreboard
+score-option-period = Only count games played in this period
+score-option-ranking = What the players are ranked by
+score-option-playlist = Only count games of this playlist
+score-option-min_games = Leave out players with fewer games
+score-option-season = Show the final standings of a past season
+score-period-today = Today
+score-period-week = Last 7 days
+score-period-month = Last 30 days
+score-period-season = This season
+score-period-all = All time
+score-ranking-total = Total points
+score-ranking-average = Points per game
+score-ranking-wins = Games won
+score-ranking-accuracy = Parts found
+score-summary = { $ranking }, { $period }
+score-playlist = Playlist: { $name }
+score-unknown-playlist = There is no playlist called "{ $name }"!
+score-empty = Nobody has played a matching game yet.
+score-season-current = Season { $season }
+score-season-archived = Final standings of season { $season }
+score-unknown-season = Season { $season } hasn't started yet!
+score-archived-options = Past seasons only keep the points, games and wins of each player, so they can't be filtered by playlist or ranked by parts found.
+
+## /season
+
+season-description = Manage the leaderboard seasons of this server
+season-show-description = Show the current season
+season-start-description = Archive the current standings and start a new season
+season-current = This is season **{ $season }**, running since { $since } (UTC).
+season-first = This is the first season, it contains every game played on this server.
+season-started = Season **{ $season }** has begun! The final standings of season { $previous } are archived, use `/score season:{ $previous }` to see them.
+season-failed = Reading or starting the season failed!
+
+## Song reports
+
+report-button = 🚩 Report song
+report-done = Thanks, the song has been reported!
+report-duplicate = You already reported this song.
+report-failed = Reporting the song failed!
+
+## /jingle
+
+jingle-description = Configure the sounds played when the bot joins or leaves
+jingle-set-description = Use a custom jingle
+jingle-option-source = URL or file name inside the assets folder
+jingle-reset-description = Go back to the bundled jingle
+jingle-enabled-description = Turn the jingles on or off
+jingle-option-enabled = Play jingles when joining and leaving
+jingle-show-description = Show the current jingles
+jingle-option-event = When the jingle is played
+jingle-event-join = Join
+jingle-event-leave = Leave
+jingle-show =
+    Jingles enabled: **{ $enabled }**
+    Join: `{ $join }`
+    Leave: `{ $leave }`
+jingle-invalid-source = `{ $source }` is neither a URL nor a file in the assets folder, or plays longer than { $seconds } seconds
+jingle-save-failed = Saving the jingle settings failed!
+
+## /config
+
+config-description = Configure the quiz defaults of this server
+config-show-description = Show the current defaults
+config-set-description = Change one or more defaults
+config-reset-description = Go back to the bundled defaults
+config-option-lobby_duration = Seconds to join a quiz
+config-option-round_duration = Seconds to guess each song
+config-option-title_threshold = Typos allowed when guessing the title
+config-option-artist_threshold = Typos allowed when guessing the artist
+config-option-skip_percentage = Percent of the players needed to skip a song
+config-option-volume = Volume in percent
+config-option-language = Language the bot speaks in this server
+config-option-share_playlists = Let other servers play the playlists added here
+config-show =
+    Lobby duration: **{ $lobby_duration }s**
+    Round duration: **{ $round_duration }s**
+    Title typos allowed: **{ $title_threshold }**
+    Artist typos allowed: **{ $artist_threshold }**
+    Skip votes needed: **{ $skip_percentage }%**
+    Volume: **{ $volume }%**
+    Language: **{ $language }**
+    Playlists shared with other servers: **{ $share_playlists }**
+config-invalid-language = `{ $code }` is not a supported language!
+config-save-failed = Saving the settings failed!
+
+## /stats
+
+stats-description = Show the quiz statistics of a player
+stats-option-user = Player to show, yourself if left out
+stats-title = Statistics of { $user }
+stats-games = Games played
+stats-wins = Wins
+stats-total-points = Total points
+stats-average-points = Points per game
+stats-title-accuracy = Titles found
+stats-artist-accuracy = Artists found
+stats-accuracy-value = { $percent }% ({ $found }/{ $asked })
+stats-reaction-time = Average reaction time
+stats-longest-streak = Longest streak
+stats-streak-value = { $rounds ->
+    [one] { $rounds } round
+   *[other] { $rounds } rounds
+}
+stats-best-playlist = Best playlist
+stats-playlist-value = { $name } ({ $average } points per game)
+stats-top-artists = Most recognized artists
+stats-artist-value = { $artist } ({ $rounds ->
+    [one] { $rounds } song
+   *[other] { $rounds } songs
+})
+stats-none = -
+stats-no-games = { $user } hasn't played a quiz on this server yet.
+stats-failed = Reading the statistics failed!
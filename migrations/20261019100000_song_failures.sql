@@ -0,0 +1 @@
+ALTER TABLE songs ADD COLUMN failures INTEGER NOT NULL DEFAULT 0;
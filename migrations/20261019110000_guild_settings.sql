@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS guild_settings
+(
+    guild_id INTEGER PRIMARY KEY NOT NULL,
+    join_jingle VARCHAR(255),
+    leave_jingle VARCHAR(255),
+    jingles_enabled BOOLEAN NOT NULL DEFAULT 1
+);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS rounds
+(
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    game_id INTEGER NOT NULL,
+    round_number INTEGER NOT NULL,
+    song_id INTEGER NOT NULL,
+    start_offset_ms INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY(game_id) REFERENCES games(id),
+    FOREIGN KEY(song_id) REFERENCES songs(id)
+);
@@ -0,0 +1,12 @@
+ALTER TABLE songs ADD COLUMN album_name VARCHAR(255);
+ALTER TABLE songs ADD COLUMN album_cover_url VARCHAR(255);
+ALTER TABLE songs ADD COLUMN release_year INTEGER;
+
+CREATE TABLE IF NOT EXISTS song_reports
+(
+    song_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    reported_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (song_id, player_id),
+    FOREIGN KEY(song_id) REFERENCES songs(id)
+);
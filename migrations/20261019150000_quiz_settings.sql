@@ -0,0 +1,6 @@
+ALTER TABLE guild_settings ADD COLUMN lobby_duration INTEGER NOT NULL DEFAULT 10;
+ALTER TABLE guild_settings ADD COLUMN round_duration INTEGER NOT NULL DEFAULT 29;
+ALTER TABLE guild_settings ADD COLUMN title_threshold INTEGER NOT NULL DEFAULT 3;
+ALTER TABLE guild_settings ADD COLUMN artist_threshold INTEGER NOT NULL DEFAULT 3;
+ALTER TABLE guild_settings ADD COLUMN skip_ratio REAL NOT NULL DEFAULT 0.32;
+ALTER TABLE guild_settings ADD COLUMN volume REAL NOT NULL DEFAULT 0.5;
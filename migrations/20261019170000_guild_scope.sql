@@ -0,0 +1,4 @@
+-- Rows from before the bot served several guilds have no guild
+ALTER TABLE games ADD COLUMN guild_id INTEGER;
+ALTER TABLE playlists ADD COLUMN guild_id INTEGER;
+ALTER TABLE guild_settings ADD COLUMN share_playlists BOOLEAN NOT NULL DEFAULT 0;
@@ -0,0 +1,13 @@
+-- Parts asked per round, rounds from before guesses were stored have none
+ALTER TABLE rounds ADD COLUMN parts INTEGER;
+
+CREATE TABLE IF NOT EXISTS guesses
+(
+    game_id INTEGER NOT NULL,
+    round_number INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    elapsed_ms INTEGER NOT NULL,
+    PRIMARY KEY (game_id, round_number, part),
+    FOREIGN KEY(game_id) REFERENCES games(id)
+);
@@ -0,0 +1,2 @@
+-- Which parts of the songs were asked, games from before it was stored have none
+ALTER TABLE games ADD COLUMN answers VARCHAR(8);
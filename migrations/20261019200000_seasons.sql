@@ -0,0 +1,21 @@
+-- Guilds without a row are in their first season, which started with their first game
+CREATE TABLE IF NOT EXISTS seasons
+(
+    guild_id INTEGER NOT NULL,
+    number INTEGER NOT NULL,
+    started_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (guild_id, number)
+);
+
+-- Final standings of the seasons that have ended
+CREATE TABLE IF NOT EXISTS season_standings
+(
+    guild_id INTEGER NOT NULL,
+    season INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    rank INTEGER NOT NULL,
+    points INTEGER NOT NULL,
+    games INTEGER NOT NULL,
+    wins INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, season, player_id)
+);
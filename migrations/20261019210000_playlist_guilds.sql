@@ -0,0 +1,9 @@
+-- Every guild that added a playlist can play it, not only the one that added it first
+CREATE TABLE playlist_guilds (
+    playlist_id INTEGER NOT NULL REFERENCES playlists(id),
+    guild_id INTEGER NOT NULL,
+    PRIMARY KEY (playlist_id, guild_id)
+);
+INSERT INTO playlist_guilds (playlist_id, guild_id)
+SELECT id, guild_id FROM playlists WHERE guild_id IS NOT NULL;
+ALTER TABLE playlists DROP COLUMN guild_id;
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use serenity::{async_trait, prelude::Mutex};
+use songbird::{Call, CoreEvent, Event, EventContext, EventHandler};
+use tokio::sync::watch;
+use tracing::info;
+
+struct ConnectionNotifier {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+#[async_trait]
+impl EventHandler for ConnectionNotifier {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        match ctx {
+            EventContext::DriverConnect(_) | EventContext::DriverReconnect(_) => {
+                let _ = self.sender.send(true);
+            }
+            EventContext::DriverDisconnect(data) => {
+                info!("Voice driver disconnected: {:?}", data.reason);
+                let _ = self.sender.send(false);
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+// Tracks whether the call currently has a working voice connection.
+// The call is kept across reconnects and quizzes, so the notifiers of an earlier
+// watch are removed first, these are the only global events the bot adds
+pub async fn watch_connection(call: &Arc<Mutex<Call>>) -> watch::Receiver<bool> {
+    let mut handler = call.lock().await;
+    handler.remove_all_global_events();
+    let (sender, receiver) = watch::channel(handler.current_connection().is_some());
+    let sender = Arc::new(sender);
+    for event in [
+        CoreEvent::DriverConnect,
+        CoreEvent::DriverReconnect,
+        CoreEvent::DriverDisconnect,
+    ] {
+        handler.add_global_event(
+            Event::Core(event),
+            ConnectionNotifier {
+                sender: sender.clone(),
+            },
+        );
+    }
+    receiver
+}
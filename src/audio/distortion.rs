@@ -0,0 +1,92 @@
+use rand::seq::SliceRandom;
+
+use crate::util::locale::Language;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Distortion {
+    Reversed,
+    PitchUp,
+    PitchDown,
+    SpedUp,
+    Muffled,
+}
+
+pub const DISTORTIONS: [Distortion; 5] = [
+    Distortion::Reversed,
+    Distortion::PitchUp,
+    Distortion::PitchDown,
+    Distortion::SpedUp,
+    Distortion::Muffled,
+];
+
+impl Distortion {
+    pub fn from_name(name: &str) -> Option<Self> {
+        DISTORTIONS.into_iter().find(|d| d.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Distortion::Reversed => "reversed",
+            Distortion::PitchUp => "pitch_up",
+            Distortion::PitchDown => "pitch_down",
+            Distortion::SpedUp => "sped_up",
+            Distortion::Muffled => "muffled",
+        }
+    }
+
+    pub fn label(&self, language: Language) -> String {
+        language.text(&format!("distortion-{}", self.name()))
+    }
+
+    // ffmpeg audio filter chain. The pitch shifts resample to a fixed rate first
+    // and then undo the tempo change, so only the pitch moves (four semitones).
+    pub fn filter(&self) -> &'static str {
+        match self {
+            Distortion::Reversed => "areverse",
+            Distortion::PitchUp => "aresample=48000,asetrate=60480,aresample=48000,atempo=0.7937",
+            Distortion::PitchDown => "aresample=48000,asetrate=38098,aresample=48000,atempo=1.26",
+            Distortion::SpedUp => "atempo=1.5",
+            Distortion::Muffled => "lowpass=f=500,lowpass=f=500",
+        }
+    }
+
+    // How much faster than the original the distorted clip plays
+    pub fn tempo(&self) -> f32 {
+        match self {
+            Distortion::SpedUp => 1.5,
+            _ => 1.0,
+        }
+    }
+
+    pub fn score_multiplier(&self) -> u32 {
+        match self {
+            Distortion::Reversed => 3,
+            _ => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum DistortionMode {
+    Off,
+    Fixed(Distortion),
+    Random,
+}
+
+impl DistortionMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "off" => Some(DistortionMode::Off),
+            "random" => Some(DistortionMode::Random),
+            _ => Distortion::from_name(name).map(DistortionMode::Fixed),
+        }
+    }
+
+    pub fn pick(&self) -> Option<Distortion> {
+        match self {
+            DistortionMode::Off => None,
+            DistortionMode::Fixed(distortion) => Some(*distortion),
+            DistortionMode::Random => DISTORTIONS.choose(&mut rand::thread_rng()).copied(),
+        }
+    }
+}
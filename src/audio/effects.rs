@@ -0,0 +1,77 @@
+use std::{
+    io::{Error as IoError, ErrorKind},
+    sync::Arc,
+};
+
+use serenity::prelude::Mutex;
+use songbird::{
+    input::{cached::Memory, error::Error as InputError},
+    Call,
+};
+use tracing::info;
+
+use super::{jingle::asset_path, source::to_input};
+
+const EFFECT_VOLUME: f32 = 0.6;
+
+pub enum Effect {
+    Tick,
+    Chime,
+    Buzzer,
+}
+
+pub struct SoundEffects {
+    tick: Memory,
+    chime: Memory,
+    buzzer: Memory,
+}
+
+impl SoundEffects {
+    pub async fn load() -> Result<Self, InputError> {
+        Ok(Self {
+            tick: load_effect("tick.wav").await?,
+            chime: load_effect("chime.wav").await?,
+            buzzer: load_effect("buzzer.wav").await?,
+        })
+    }
+}
+
+async fn load_effect(name: &str) -> Result<Memory, InputError> {
+    let path = asset_path(name).ok_or_else(|| {
+        InputError::Io(IoError::new(
+            ErrorKind::NotFound,
+            format!("{} is not a valid asset", name),
+        ))
+    })?;
+    let memory = Memory::new(songbird::ffmpeg(path).await?)?;
+    let _ = memory.raw.spawn_loader();
+    Ok(memory)
+}
+
+// Effects are mixed on top of whatever is currently playing in the call
+#[derive(Clone)]
+pub struct EffectPlayer {
+    effects: Arc<SoundEffects>,
+    call: Arc<Mutex<Call>>,
+}
+
+impl EffectPlayer {
+    pub fn new(effects: Arc<SoundEffects>, call: Arc<Mutex<Call>>) -> Self {
+        Self { effects, call }
+    }
+
+    pub async fn play(&self, effect: Effect) {
+        let memory = match effect {
+            Effect::Tick => &self.effects.tick,
+            Effect::Chime => &self.effects.chime,
+            Effect::Buzzer => &self.effects.buzzer,
+        };
+        match to_input(memory) {
+            Ok(source) => {
+                let mut handler = self.call.lock().await;
+                let _ = handler.play_source(source).set_volume(EFFECT_VOLUME);
+            }
+            Err(why) => info!("Err starting sound effect: {:?}", why),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    ffi::OsString,
+    io::{Error as IoError, ErrorKind},
+    path::{Component, Path, PathBuf},
+    time::Duration,
+};
+
+use songbird::input::{error::Error as InputError, Input};
+
+use super::source::{load_limited_source, probe_length, resolve_audio_url};
+
+pub const ASSETS_DIR: &str = "assets";
+pub const DEFAULT_JOIN_JINGLE: &str = "join.wav";
+pub const DEFAULT_LEAVE_JINGLE: &str = "leave.wav";
+// Longer jingles would play over the first round or keep the bot in the channel
+pub const MAX_JINGLE_LENGTH: Duration = Duration::from_secs(10);
+
+pub fn is_url(location: &str) -> bool {
+    location.starts_with("http://") || location.starts_with("https://")
+}
+
+// Local jingles have to live inside the assets folder
+pub fn asset_path(name: &str) -> Option<PathBuf> {
+    let path = Path::new(name);
+    let is_relative = path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if name.is_empty() || !is_relative {
+        return None;
+    }
+    Some(Path::new(ASSETS_DIR).join(path))
+}
+
+// What ffmpeg has to open: the asset file, or the audio stream behind the URL
+async fn locate_jingle(location: &str) -> Result<OsString, InputError> {
+    if is_url(location) {
+        return resolve_audio_url(location).await.map(OsString::from);
+    }
+    match asset_path(location) {
+        Some(path) if path.is_file() => Ok(path.into_os_string()),
+        _ => Err(InputError::Io(IoError::new(
+            ErrorKind::InvalidInput,
+            format!("{} is not a file inside the assets folder", location),
+        ))),
+    }
+}
+
+// Sources of unknown length are accepted, playback stops after MAX_JINGLE_LENGTH anyway
+pub async fn is_valid_jingle(location: &str) -> bool {
+    match locate_jingle(location).await {
+        Ok(path) => probe_length(path)
+            .await
+            .is_none_or(|length| length <= MAX_JINGLE_LENGTH),
+        Err(_) => false,
+    }
+}
+
+pub async fn load_jingle(location: &str) -> Result<Input, InputError> {
+    load_limited_source(locate_jingle(location).await?, MAX_JINGLE_LENGTH).await
+}
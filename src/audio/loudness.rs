@@ -0,0 +1,83 @@
+use std::process::Stdio;
+
+use regex::Regex;
+use sqlx::SqlitePool;
+use tokio::process::Command;
+use tracing::info;
+
+use crate::database::song::{read_songs_without_loudness, update_song_loudness, Song};
+
+use super::source::is_direct_audio_url;
+
+// Every preview is scaled towards this integrated loudness (EBU R128)
+const TARGET_LOUDNESS: f64 = -16.0;
+const MIN_GAIN: f32 = 0.1;
+const MAX_GAIN: f32 = 3.0;
+
+// Only the summary line starts with "I:", the per frame log has it mid line
+const INTEGRATED_LOUDNESS_REGEX: &str = r"(?m)^\s*I:\s+(-?[0-9.]+) LUFS";
+
+pub fn loudness_gain(loudness: Option<f64>) -> f32 {
+    match loudness {
+        Some(loudness) => {
+            let gain = 10f64.powf((TARGET_LOUDNESS - loudness) / 20.0) as f32;
+            gain.clamp(MIN_GAIN, MAX_GAIN)
+        }
+        None => 1.0,
+    }
+}
+
+pub async fn measure_loudness(url: &str) -> Option<f64> {
+    if !is_direct_audio_url(url) {
+        return None;
+    }
+    let output = Command::new("ffmpeg")
+        .args(["-hide_banner", "-nostats", "-i", url])
+        .args(["-af", "ebur128", "-f", "null", "-"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .await;
+    let output = match output {
+        Ok(output) if output.status.success() => output,
+        Ok(output) => {
+            info!("Loudness analysis of {} failed: {}", url, output.status);
+            return None;
+        }
+        Err(why) => {
+            info!("Err running ffmpeg for loudness analysis: {:?}", why);
+            return None;
+        }
+    };
+    let log = String::from_utf8_lossy(&output.stderr);
+    Regex::new(INTEGRATED_LOUDNESS_REGEX)
+        .unwrap()
+        .captures_iter(&log)
+        .last()
+        .and_then(|captures| captures[1].parse::<f64>().ok())
+        .filter(|loudness| loudness.is_finite())
+}
+
+// Measures a song and stores the result so it only has to be done once
+pub async fn analyze_song(database: &SqlitePool, song: &Song) -> Option<f64> {
+    let loudness = measure_loudness(&song.preview_url).await?;
+    if let Err(why) = update_song_loudness(database, song.id, loudness).await {
+        info!("Failed to store loudness of song {}: {:?}", song.id, why);
+    }
+    Some(loudness)
+}
+
+pub async fn analyze_playlist(database: SqlitePool, playlist_id: i64) {
+    let songs = match read_songs_without_loudness(&database, playlist_id).await {
+        Ok(songs) => songs,
+        Err(why) => {
+            info!("Failed to read songs for loudness analysis: {:?}", why);
+            return;
+        }
+    };
+    info!("Analyzing loudness of {} songs", songs.len());
+    for song in songs {
+        analyze_song(&database, &song).await;
+    }
+}
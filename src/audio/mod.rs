@@ -0,0 +1,7 @@
+pub mod connection;
+pub mod distortion;
+pub mod effects;
+pub mod jingle;
+pub mod loudness;
+pub mod playback;
+pub mod source;
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use serenity::async_trait;
+use songbird::{tracks::TrackHandle, Event, EventContext, EventHandler, TrackEvent};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaybackEvent {
+    Started,
+    Ended,
+}
+
+struct PlaybackNotifier {
+    sender: UnboundedSender<PlaybackEvent>,
+    event: PlaybackEvent,
+}
+
+#[async_trait]
+impl EventHandler for PlaybackNotifier {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        if let EventContext::Track(_) = ctx {
+            let _ = self.sender.send(self.event);
+        }
+        None
+    }
+}
+
+// TrackEvent::Play only fires when resuming, so the start is detected as the
+// first few milliseconds of actual playback instead.
+pub fn watch_playback(track: &TrackHandle) -> UnboundedReceiver<PlaybackEvent> {
+    let (sender, receiver) = unbounded_channel();
+    let _ = track.add_event(
+        Event::Delayed(Duration::from_millis(20)),
+        PlaybackNotifier {
+            sender: sender.clone(),
+            event: PlaybackEvent::Started,
+        },
+    );
+    let _ = track.add_event(
+        Event::Track(TrackEvent::End),
+        PlaybackNotifier {
+            sender,
+            event: PlaybackEvent::Ended,
+        },
+    );
+    receiver
+}
@@ -0,0 +1,189 @@
+use std::{convert::TryInto, ffi::OsStr, io::Error as IoError, process::Stdio, time::Duration};
+
+use rand::Rng;
+use songbird::input::{cached::Memory, error::Error as InputError, Input};
+use sqlx::SqlitePool;
+use tokio::{process::Command, task::JoinHandle};
+
+use crate::database::song::Song;
+
+use super::{distortion::Distortion, loudness::analyze_song};
+
+pub struct PreparedSource {
+    pub memory: Memory,
+    pub loudness: Option<f64>,
+}
+
+pub type PendingSource = JoinHandle<Result<PreparedSource, InputError>>;
+
+pub struct UpcomingTrack {
+    pub song: Song,
+    pub distortion: Option<Distortion>,
+    pub source: PendingSource,
+}
+
+// Used when ffmpeg could not tell how long the clip is
+const PREVIEW_LENGTH: Duration = Duration::from_secs(30);
+const MIN_REMAINING_PLAYBACK: Duration = Duration::from_secs(15);
+
+// Spotify previews and other plain audio files, which ffmpeg can read directly
+const DIRECT_AUDIO_REGEX: &str =
+    r"^https?://(p\.scdn\.co/mp3-preview/|[^?#]+\.(mp3|ogg|oga|wav|flac|m4a|aac|opus)([?#].*)?$)";
+
+const HTTP_INPUT_ARGS: [&str; 6] = [
+    "-reconnect",
+    "1",
+    "-reconnect_streamed",
+    "1",
+    "-reconnect_delay_max",
+    "5",
+];
+
+// Same output format songbird uses for its own ffmpeg inputs. The channel count
+// is left untouched so it matches what songbird probes from the source.
+const PCM_OUTPUT_ARGS: [&str; 7] = ["-f", "f32le", "-ar", "48000", "-acodec", "pcm_f32le", "-"];
+
+pub fn is_direct_audio_url(url: &str) -> bool {
+    regex::Regex::new(DIRECT_AUDIO_REGEX).unwrap().is_match(url)
+}
+
+// Direct audio URLs are streamed by ffmpeg, everything else is treated as a
+// page that yt-dlp has to resolve first.
+pub async fn load_filtered_source(url: &str, filter: Option<&str>) -> Result<Input, InputError> {
+    let mut output_args = Vec::new();
+    if let Some(filter) = filter {
+        output_args.extend(["-af", filter]);
+    }
+    output_args.extend(PCM_OUTPUT_ARGS);
+
+    if is_direct_audio_url(url) {
+        songbird::input::ffmpeg_optioned(url, &HTTP_INPUT_ARGS, &output_args).await
+    } else if filter.is_some() {
+        // songbird's ytdl input can't take ffmpeg filters, so only let yt-dlp find the stream
+        let stream_url = resolve_stream_url(url).await?;
+        songbird::input::ffmpeg_optioned(stream_url, &HTTP_INPUT_ARGS, &output_args).await
+    } else {
+        songbird::ytdl(url).await
+    }
+}
+
+// ffmpeg stops reading after max_length. Takes a local file or a URL ffmpeg can read directly.
+pub async fn load_limited_source<P: AsRef<OsStr>>(
+    path: P,
+    max_length: Duration,
+) -> Result<Input, InputError> {
+    let max_length = max_length.as_secs_f64().to_string();
+    let mut output_args = vec!["-t", max_length.as_str()];
+    output_args.extend(PCM_OUTPUT_ARGS);
+    songbird::input::ffmpeg_optioned(path, &[], &output_args).await
+}
+
+// Length of a local file or a URL ffmpeg can read directly, None if ffprobe can't tell
+pub async fn probe_length<P: AsRef<OsStr>>(path: P) -> Option<Duration> {
+    let output = Command::new("ffprobe")
+        .args([
+            "-v",
+            "quiet",
+            "-show_entries",
+            "format=duration",
+            "-of",
+            "csv=p=0",
+        ])
+        .arg(path)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .await
+        .ok()?;
+    let seconds = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+// Direct audio URLs are kept, pages are resolved to their stream by yt-dlp
+pub async fn resolve_audio_url(url: &str) -> Result<String, InputError> {
+    if is_direct_audio_url(url) {
+        Ok(url.to_string())
+    } else {
+        resolve_stream_url(url).await
+    }
+}
+
+async fn resolve_stream_url(url: &str) -> Result<String, InputError> {
+    let output = Command::new("yt-dlp")
+        .args(["-f", "bestaudio/best", "--no-playlist", "--get-url", url])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .await?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match stdout.lines().next() {
+        Some(stream_url) if output.status.success() => Ok(stream_url.trim().to_string()),
+        _ => Err(InputError::Io(IoError::other(format!(
+            "yt-dlp could not resolve {}",
+            url
+        )))),
+    }
+}
+
+// Resolves the source and starts buffering it into memory in the background,
+// so the track can start instantly once its round begins.
+pub async fn prepare_source(
+    url: String,
+    distortion: Option<Distortion>,
+) -> Result<Memory, InputError> {
+    let filter = distortion.map(|distortion| distortion.filter());
+    let source = load_filtered_source(&url, filter).await?;
+    let memory = Memory::new(source)?;
+    let _ = memory.raw.spawn_loader();
+    Ok(memory)
+}
+
+// Songs imported before loudness analysis existed are measured while buffering
+async fn prepare_track(
+    track: Song,
+    distortion: Option<Distortion>,
+    database: SqlitePool,
+) -> Result<PreparedSource, InputError> {
+    let (memory, loudness) = match track.loudness {
+        Some(loudness) => (
+            prepare_source(track.preview_url.clone(), distortion).await,
+            Some(loudness),
+        ),
+        None => tokio::join!(
+            prepare_source(track.preview_url.clone(), distortion),
+            analyze_song(&database, &track)
+        ),
+    };
+    Ok(PreparedSource {
+        memory: memory?,
+        loudness,
+    })
+}
+
+pub fn preload(track: Song, distortion: Option<Distortion>, database: SqlitePool) -> UpcomingTrack {
+    let source = tokio::spawn(prepare_track(track.clone(), distortion, database));
+    UpcomingTrack {
+        song: track,
+        distortion,
+        source,
+    }
+}
+
+pub fn to_input(memory: &Memory) -> Result<Input, InputError> {
+    memory.new_handle().try_into()
+}
+
+// Picks a start position that still leaves enough of the clip to guess from
+pub fn random_start_offset(duration: Option<Duration>) -> Duration {
+    let latest_start = duration
+        .unwrap_or(PREVIEW_LENGTH)
+        .saturating_sub(MIN_REMAINING_PLAYBACK);
+    if latest_start.is_zero() {
+        return Duration::ZERO;
+    }
+    let offset_ms = rand::thread_rng().gen_range(0..=latest_start.as_millis() as u64);
+    Duration::from_millis(offset_ms)
+}
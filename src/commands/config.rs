@@ -0,0 +1,210 @@
+use std::ops::RangeInclusive;
+
+use serenity::{
+    builder::CreateApplicationCommand,
+    model::{
+        prelude::{
+            command::CommandOptionType,
+            interaction::{
+                application_command::{ApplicationCommandInteraction, CommandDataOption},
+                InteractionResponseType,
+            },
+        },
+        Permissions,
+    },
+    prelude::Context,
+};
+use tracing::info;
+
+use crate::{
+    commands::quiz_config::{read_int, LOBBY_DURATIONS, ROUND_DURATIONS, THRESHOLDS},
+    database::guild_settings::{read_guild_settings, save_guild_settings, GuildSettings},
+    util::{
+        locale::{localize_command, localize_option, Language, LANGUAGES},
+        util::{get_bool_option, get_string_option},
+    },
+    BotDatabase,
+};
+
+// Percentages, stored as fractions
+const SKIP_PERCENTAGES: RangeInclusive<i64> = 1..=100;
+const VOLUME_PERCENTAGES: RangeInclusive<i64> = 0..=200;
+
+pub fn register_config(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("config"), "config-description")
+        .default_member_permissions(Permissions::ADMINISTRATOR)
+        .create_option(|option| {
+            localize_option(option.name("show"), "config-show-description")
+                .kind(CommandOptionType::SubCommand)
+        })
+        .create_option(|option| {
+            localize_option(option.name("set"), "config-set-description")
+                .kind(CommandOptionType::SubCommand)
+                .create_sub_option(|o| {
+                    localize_option(o.name("lobby_duration"), "config-option-lobby_duration")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(*LOBBY_DURATIONS.start())
+                        .max_int_value(*LOBBY_DURATIONS.end())
+                })
+                .create_sub_option(|o| {
+                    localize_option(o.name("round_duration"), "config-option-round_duration")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(*ROUND_DURATIONS.start())
+                        .max_int_value(*ROUND_DURATIONS.end())
+                })
+                .create_sub_option(|o| {
+                    localize_option(o.name("title_threshold"), "config-option-title_threshold")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(*THRESHOLDS.start())
+                        .max_int_value(*THRESHOLDS.end())
+                })
+                .create_sub_option(|o| {
+                    localize_option(o.name("artist_threshold"), "config-option-artist_threshold")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(*THRESHOLDS.start())
+                        .max_int_value(*THRESHOLDS.end())
+                })
+                .create_sub_option(|o| {
+                    localize_option(o.name("skip_percentage"), "config-option-skip_percentage")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(*SKIP_PERCENTAGES.start())
+                        .max_int_value(*SKIP_PERCENTAGES.end())
+                })
+                .create_sub_option(|o| {
+                    localize_option(o.name("volume"), "config-option-volume")
+                        .kind(CommandOptionType::Integer)
+                        .min_int_value(*VOLUME_PERCENTAGES.start())
+                        .max_int_value(*VOLUME_PERCENTAGES.end())
+                })
+                .create_sub_option(|o| {
+                    localize_option(o.name("language"), "config-option-language")
+                        .kind(CommandOptionType::String);
+                    // Every language is listed in its own name
+                    for language in LANGUAGES {
+                        o.add_string_choice(language.text("language-name"), language.code());
+                    }
+                    o
+                })
+                .create_sub_option(|o| {
+                    localize_option(o.name("share_playlists"), "config-option-share_playlists")
+                        .kind(CommandOptionType::Boolean)
+                })
+        })
+        .create_option(|option| {
+            localize_option(option.name("reset"), "config-reset-description")
+                .kind(CommandOptionType::SubCommand)
+        })
+}
+
+fn describe(settings: &GuildSettings) -> String {
+    let language = settings.language();
+    language.text_with(
+        "config-show",
+        &[
+            ("lobby_duration", settings.lobby_duration.into()),
+            ("round_duration", settings.round_duration.into()),
+            ("title_threshold", settings.title_threshold.into()),
+            ("artist_threshold", settings.artist_threshold.into()),
+            (
+                "skip_percentage",
+                (settings.skip_ratio * 100.0).round().into(),
+            ),
+            ("volume", (settings.volume * 100.0).round().into()),
+            ("language", language.text("language-name").into()),
+            (
+                "share_playlists",
+                settings.share_playlists.to_string().into(),
+            ),
+        ],
+    )
+}
+
+pub async fn run_config(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id.0 as i64,
+        None => return,
+    };
+    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
+    let mut settings = read_guild_settings(&database, guild_id).await.unwrap();
+    let language = settings.language();
+
+    let subcommand = interaction
+        .data
+        .options
+        .get(0)
+        .expect("Expected a subcommand");
+    let options = &subcommand.options;
+    let content = match subcommand.name.as_str() {
+        "set" => match apply_options(&mut settings, options, language) {
+            Ok(_) => save_settings(&database, &settings).await,
+            Err(why) => why,
+        },
+        "reset" => {
+            // Jingles have their own command and are kept
+            let defaults = GuildSettings::new(guild_id);
+            settings = GuildSettings {
+                join_jingle: settings.join_jingle,
+                leave_jingle: settings.leave_jingle,
+                jingles_enabled: settings.jingles_enabled,
+                ..defaults
+            };
+            save_settings(&database, &settings).await
+        }
+        _ => describe(&settings),
+    };
+
+    interaction
+        .create_interaction_response(ctx, |f| {
+            f.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|m| m.ephemeral(true).content(content))
+        })
+        .await
+        .unwrap();
+}
+
+fn apply_options(
+    settings: &mut GuildSettings,
+    options: &[CommandDataOption],
+    language: Language,
+) -> Result<(), String> {
+    if let Some(seconds) = read_int(options, "lobby_duration", LOBBY_DURATIONS, language)? {
+        settings.lobby_duration = seconds;
+    }
+    if let Some(seconds) = read_int(options, "round_duration", ROUND_DURATIONS, language)? {
+        settings.round_duration = seconds;
+    }
+    if let Some(typos) = read_int(options, "title_threshold", THRESHOLDS, language)? {
+        settings.title_threshold = typos;
+    }
+    if let Some(typos) = read_int(options, "artist_threshold", THRESHOLDS, language)? {
+        settings.artist_threshold = typos;
+    }
+    if let Some(percent) = read_int(options, "skip_percentage", SKIP_PERCENTAGES, language)? {
+        settings.skip_ratio = percent as f64 / 100.0;
+    }
+    if let Some(percent) = read_int(options, "volume", VOLUME_PERCENTAGES, language)? {
+        settings.volume = percent as f64 / 100.0;
+    }
+    if let Some(share) = get_bool_option(options, "share_playlists") {
+        settings.share_playlists = share;
+    }
+    if let Some(code) = get_string_option(options, "language") {
+        match Language::from_code(&code) {
+            Some(language) => settings.language = language.code().to_string(),
+            None => {
+                return Err(language.text_with("config-invalid-language", &[("code", code.into())]))
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn save_settings(database: &sqlx::SqlitePool, settings: &GuildSettings) -> String {
+    match save_guild_settings(database, settings).await {
+        Ok(_) => describe(settings),
+        Err(why) => {
+            info!("Failed to save guild settings: {:?}", why);
+            settings.language().text("config-save-failed")
+        }
+    }
+}
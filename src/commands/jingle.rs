@@ -0,0 +1,171 @@
+use serenity::{
+    builder::{CreateApplicationCommand, CreateApplicationCommandOption},
+    model::{
+        prelude::{
+            command::CommandOptionType,
+            interaction::{
+                application_command::ApplicationCommandInteraction, InteractionResponseType,
+            },
+        },
+        Permissions,
+    },
+    prelude::Context,
+};
+use tracing::info;
+
+use crate::{
+    audio::jingle::{
+        is_valid_jingle, DEFAULT_JOIN_JINGLE, DEFAULT_LEAVE_JINGLE, MAX_JINGLE_LENGTH,
+    },
+    database::guild_settings::{read_guild_settings, save_guild_settings, GuildSettings},
+    util::{
+        locale::{add_localized_choice, localize_command, localize_option, Language},
+        util::{get_bool_option, get_string_option},
+    },
+    BotDatabase,
+};
+
+pub fn register_jingle(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("jingle"), "jingle-description")
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .create_option(|option| {
+            localize_option(option.name("set"), "jingle-set-description")
+                .kind(CommandOptionType::SubCommand)
+                .create_sub_option(|o| add_event_option(o))
+                .create_sub_option(|o| {
+                    localize_option(o.name("source"), "jingle-option-source")
+                        .kind(CommandOptionType::String)
+                        .required(true)
+                })
+        })
+        .create_option(|option| {
+            localize_option(option.name("reset"), "jingle-reset-description")
+                .kind(CommandOptionType::SubCommand)
+                .create_sub_option(|o| add_event_option(o))
+        })
+        .create_option(|option| {
+            localize_option(option.name("enabled"), "jingle-enabled-description")
+                .kind(CommandOptionType::SubCommand)
+                .create_sub_option(|o| {
+                    localize_option(o.name("enabled"), "jingle-option-enabled")
+                        .kind(CommandOptionType::Boolean)
+                        .required(true)
+                })
+        })
+        .create_option(|option| {
+            localize_option(option.name("show"), "jingle-show-description")
+                .kind(CommandOptionType::SubCommand)
+        })
+}
+
+fn add_event_option(
+    option: &mut CreateApplicationCommandOption,
+) -> &mut CreateApplicationCommandOption {
+    localize_option(option.name("event"), "jingle-option-event")
+        .kind(CommandOptionType::String)
+        .required(true);
+    add_localized_choice(option, "jingle-event-join", "join");
+    add_localized_choice(option, "jingle-event-leave", "leave")
+}
+
+fn describe(settings: &GuildSettings, language: Language) -> String {
+    language.text_with(
+        "jingle-show",
+        &[
+            ("enabled", settings.jingles_enabled.to_string().into()),
+            (
+                "join",
+                settings
+                    .join_jingle
+                    .as_deref()
+                    .unwrap_or(DEFAULT_JOIN_JINGLE)
+                    .into(),
+            ),
+            (
+                "leave",
+                settings
+                    .leave_jingle
+                    .as_deref()
+                    .unwrap_or(DEFAULT_LEAVE_JINGLE)
+                    .into(),
+            ),
+        ],
+    )
+}
+
+pub async fn run_jingle(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id.0 as i64,
+        None => return,
+    };
+    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
+    let mut settings = read_guild_settings(&database, guild_id).await.unwrap();
+    let language = settings.language();
+
+    // Checking a new source can take longer than Discord waits for the response
+    interaction
+        .create_interaction_response(ctx, |f| {
+            f.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                .interaction_response_data(|m| m.ephemeral(true))
+        })
+        .await
+        .unwrap();
+
+    let subcommand = interaction
+        .data
+        .options
+        .get(0)
+        .expect("Expected a subcommand");
+    let options = &subcommand.options;
+    let content = match subcommand.name.as_str() {
+        "set" => {
+            let source = get_string_option(options, "source").unwrap_or_default();
+            if !is_valid_jingle(&source).await {
+                language.text_with(
+                    "jingle-invalid-source",
+                    &[
+                        ("source", source.into()),
+                        ("seconds", MAX_JINGLE_LENGTH.as_secs().into()),
+                    ],
+                )
+            } else {
+                match get_string_option(options, "event").as_deref() {
+                    Some("leave") => settings.leave_jingle = Some(source),
+                    _ => settings.join_jingle = Some(source),
+                }
+                save_settings(&database, &settings, language).await
+            }
+        }
+        "reset" => {
+            match get_string_option(options, "event").as_deref() {
+                Some("leave") => settings.leave_jingle = None,
+                _ => settings.join_jingle = None,
+            }
+            save_settings(&database, &settings, language).await
+        }
+        "enabled" => {
+            settings.jingles_enabled = get_bool_option(options, "enabled").unwrap_or(true);
+            save_settings(&database, &settings, language).await
+        }
+        _ => describe(&settings, language),
+    };
+
+    interaction
+        .edit_original_interaction_response(ctx, |m| m.content(content))
+        .await
+        .unwrap();
+}
+
+async fn save_settings(
+    database: &sqlx::SqlitePool,
+    settings: &GuildSettings,
+    language: Language,
+) -> String {
+    match save_guild_settings(database, settings).await {
+        Ok(_) => describe(settings, language),
+        Err(why) => {
+            info!("Failed to save guild settings: {:?}", why);
+            language.text("jingle-save-failed")
+        }
+    }
+}
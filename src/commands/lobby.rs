@@ -0,0 +1,358 @@
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use serenity::{
+    builder::{CreateComponents, CreateEmbed},
+    collector::ComponentInteractionCollectorBuilder,
+    futures::StreamExt,
+    model::{
+        prelude::{
+            component::ButtonStyle,
+            interaction::{
+                application_command::ApplicationCommandInteraction,
+                message_component::MessageComponentInteraction, InteractionResponseType,
+            },
+            ChannelId, UserId,
+        },
+        user::User,
+    },
+    prelude::{Context, RwLock},
+};
+use tokio::time::{Instant, MissedTickBehavior};
+use tracing::info;
+
+use crate::util::locale::Language;
+
+pub const DEFAULT_LOBBY_DURATION: u64 = 10;
+pub const DEFAULT_MIN_PLAYERS: usize = 1;
+// Discord doesn't allow more options in a select menu
+const MAX_KICK_OPTIONS: usize = 25;
+
+pub struct LobbySettings {
+    pub duration: Duration,
+    pub min_players: usize,
+    pub language: Language,
+}
+
+struct Lobby {
+    host: UserId,
+    min_players: usize,
+    language: Language,
+    players: HashSet<User>,
+    ready: HashSet<UserId>,
+}
+
+impl Lobby {
+    fn has_enough_players(&self) -> bool {
+        !self.players.is_empty() && self.players.len() >= self.min_players
+    }
+
+    // Nobody has to wait for the timer once everyone is ready
+    fn everyone_ready(&self) -> bool {
+        self.has_enough_players()
+            && self
+                .players
+                .iter()
+                .all(|player| self.ready.contains(&player.id))
+    }
+
+    fn create_embed(&self) -> CreateEmbed {
+        let mut e = CreateEmbed::default();
+        e.color(0xff7c1e);
+        e.title(self.language.text("lobby-title"));
+        let player_string = self
+            .players
+            .iter()
+            .map(|player| {
+                if self.ready.contains(&player.id) {
+                    format!("✅ {}", player)
+                } else {
+                    format!("⏳ {}", player)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        e.field(
+            self.language.text("lobby-participants"),
+            player_string,
+            false,
+        );
+        if self.min_players > 1 {
+            e.footer(|f| {
+                f.text(
+                    self.language
+                        .text_with("lobby-min-players", &[("count", self.min_players.into())]),
+                )
+            });
+        }
+        e
+    }
+
+    fn create_components(&self) -> CreateComponents {
+        let mut c = CreateComponents::default();
+        c.create_action_row(|row| {
+            row.create_button(|b| {
+                b.custom_id("join_button");
+                b.label(self.language.text("lobby-join"));
+                b.style(ButtonStyle::Success)
+            });
+            row.create_button(|b| {
+                b.custom_id("leave_button");
+                b.label(self.language.text("lobby-leave"));
+                b.style(ButtonStyle::Danger)
+            });
+            row.create_button(|b| {
+                b.custom_id("ready_button");
+                b.label(self.language.text("lobby-ready"));
+                b.style(ButtonStyle::Primary)
+            });
+            row.create_button(|b| {
+                b.custom_id("start_button");
+                b.label(self.language.text("lobby-start"));
+                b.style(ButtonStyle::Secondary)
+            })
+        });
+        if !self.players.is_empty() {
+            c.create_action_row(|row| {
+                row.create_select_menu(|menu| {
+                    menu.custom_id("kick_select");
+                    menu.placeholder(self.language.text("lobby-kick"));
+                    menu.options(|o| {
+                        for player in self.players.iter().take(MAX_KICK_OPTIONS) {
+                            o.create_option(|option| option.label(&player.name).value(player.id));
+                        }
+                        o
+                    })
+                })
+            });
+        }
+        c
+    }
+}
+
+// Collects the players of a quiz. Returns None if not enough players joined.
+pub async fn run_lobby(
+    ctx: &Context,
+    interaction: &ApplicationCommandInteraction,
+    settings: LobbySettings,
+) -> Option<HashSet<User>> {
+    let mut lobby = Lobby {
+        host: interaction.user.id,
+        min_players: settings.min_players,
+        language: settings.language,
+        players: HashSet::new(),
+        ready: HashSet::new(),
+    };
+    let deadline = Instant::now() + settings.duration;
+    interaction
+        .create_interaction_response(&ctx.http, |r| {
+            r.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|d| {
+                    d.content(countdown_text(deadline, lobby.language))
+                        .set_embed(lobby.create_embed())
+                        .set_components(lobby.create_components())
+                })
+        })
+        .await
+        .ok()?;
+    let mut message = interaction.get_interaction_response(ctx).await.ok()?;
+
+    let mut response_collector = message
+        .await_component_interactions(ctx)
+        .timeout(settings.duration + Duration::from_secs(1))
+        .build();
+    let mut timer = tokio::time::interval(Duration::from_secs(1));
+    timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        tokio::select! {
+            _ = timer.tick() => {
+                if Instant::now() >= deadline {
+                    break;
+                }
+                if let Err(why) = message
+                    .edit(ctx, |m| {
+                        m.content(countdown_text(deadline, lobby.language))
+                            .set_embed(lobby.create_embed())
+                            .set_components(lobby.create_components())
+                    })
+                    .await
+                {
+                    info!("Err updating lobby: {:?}", why);
+                }
+            }
+            event = response_collector.next() => {
+                let event = match event {
+                    Some(event) => event,
+                    None => break,
+                };
+                let start_now = handle_lobby_event(ctx, &event, &mut lobby, deadline).await;
+                info!("{:?}", lobby.players);
+                if start_now || lobby.everyone_ready() {
+                    break;
+                }
+            }
+        }
+    }
+
+    let content = if lobby.has_enough_players() {
+        lobby.language.text("lobby-starting")
+    } else {
+        lobby.language.text("lobby-cancelled")
+    };
+    let _ = message
+        .edit(ctx, |m| {
+            m.content(content)
+                .set_embed(lobby.create_embed())
+                .set_components(CreateComponents::default())
+        })
+        .await;
+
+    if lobby.has_enough_players() {
+        Some(lobby.players)
+    } else {
+        None
+    }
+}
+
+// Applies a button press or kick to the lobby. Returns true if the host started the quiz.
+async fn handle_lobby_event(
+    ctx: &Context,
+    event: &MessageComponentInteraction,
+    lobby: &mut Lobby,
+    deadline: Instant,
+) -> bool {
+    let is_host = event.user.id == lobby.host;
+    let mut start_now = false;
+    let rejection = match event.data.custom_id.as_str() {
+        "join_button" => {
+            lobby.players.insert(event.user.clone());
+            None
+        }
+        "leave_button" => {
+            lobby.players.remove(&event.user);
+            lobby.ready.remove(&event.user.id);
+            None
+        }
+        "ready_button" => {
+            if !lobby.players.contains(&event.user) {
+                Some("lobby-join-first")
+            } else {
+                if !lobby.ready.remove(&event.user.id) {
+                    lobby.ready.insert(event.user.id);
+                }
+                None
+            }
+        }
+        "start_button" if !is_host => Some("lobby-start-host-only"),
+        "start_button" if !lobby.has_enough_players() => Some("lobby-not-enough-players"),
+        "start_button" => {
+            start_now = true;
+            None
+        }
+        "kick_select" if !is_host => Some("lobby-kick-host-only"),
+        "kick_select" => {
+            let kicked = event
+                .data
+                .values
+                .first()
+                .and_then(|value| value.parse::<u64>().ok());
+            if let Some(kicked) = kicked {
+                lobby.players.retain(|player| player.id.0 != kicked);
+                lobby.ready.remove(&UserId(kicked));
+            }
+            None
+        }
+        _ => None,
+    };
+
+    let _ = event
+        .create_interaction_response(ctx, |r| match rejection {
+            Some(rejection) => r
+                .kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|d| {
+                    d.ephemeral(true).content(lobby.language.text(rejection))
+                }),
+            None => r
+                .kind(InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|d| {
+                    d.content(countdown_text(deadline, lobby.language))
+                        .set_embed(lobby.create_embed())
+                        .set_components(lobby.create_components())
+                }),
+        })
+        .await;
+    start_now
+}
+
+fn countdown_text(deadline: Instant, language: Language) -> String {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    language.text_with(
+        "lobby-countdown",
+        &[(
+            "seconds",
+            ((remaining.as_millis() as f64 / 1000.0).ceil() as u64).into(),
+        )],
+    )
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LateJoin {
+    Off,
+    Zero,
+    // Late players start with the score of the last place
+    CatchUp,
+}
+
+impl LateJoin {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "off" => Some(LateJoin::Off),
+            "zero" => Some(LateJoin::Zero),
+            "catch_up" => Some(LateJoin::CatchUp),
+            _ => None,
+        }
+    }
+}
+
+// Handles the join late and spectate buttons of the round status messages
+// for as long as the quiz is running
+pub async fn run_late_join(
+    ctx: Context,
+    channel_id: ChannelId,
+    late_join: LateJoin,
+    players: Arc<RwLock<HashSet<User>>>,
+    spectators: Arc<RwLock<HashSet<User>>>,
+    language: Language,
+) {
+    let mut collector = ComponentInteractionCollectorBuilder::new(&ctx)
+        .channel_id(channel_id)
+        .filter(|i| {
+            matches!(
+                i.data.custom_id.as_str(),
+                "late_join_button" | "spectate_button"
+            )
+        })
+        .build();
+    while let Some(event) = collector.next().await {
+        let is_player = players.read().await.contains(&event.user);
+        let content = match event.data.custom_id.as_str() {
+            _ if is_player => "late-join-already-playing",
+            "late_join_button" if late_join == LateJoin::Off => "late-join-disabled",
+            "late_join_button" => {
+                spectators.write().await.remove(&event.user);
+                players.write().await.insert(event.user.clone());
+                "late-join-joined"
+            }
+            _ => {
+                spectators.write().await.insert(event.user.clone());
+                "late-join-spectating"
+            }
+        };
+        let content = language.text(content);
+        let _ = event
+            .create_interaction_response(&ctx, |r| {
+                r.kind(InteractionResponseType::ChannelMessageWithSource)
+                    .interaction_response_data(|d| d.ephemeral(true).content(content))
+            })
+            .await;
+    }
+}
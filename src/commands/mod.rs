@@ -1,3 +1,13 @@
+pub mod config;
+pub mod jingle;
+pub mod lobby;
+pub mod pause;
 pub mod quiz;
+pub mod quiz_config;
+pub mod report;
+pub mod round_status;
 pub mod score;
+pub mod season;
 pub mod skip;
+pub mod stats;
+pub mod volume;
@@ -0,0 +1,77 @@
+use serenity::{
+    builder::CreateApplicationCommand,
+    model::prelude::interaction::{
+        application_command::ApplicationCommandInteraction, InteractionResponseType,
+    },
+    prelude::Context,
+};
+
+use crate::{
+    util::locale::{guild_language, localize_command},
+    BotRunningQuizzes,
+};
+
+pub fn register_pause(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("pause"), "pause-description")
+}
+
+pub fn register_resume(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("resume"), "resume-description")
+}
+
+pub async fn run_pause(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    set_paused(ctx, interaction, true).await;
+}
+
+pub async fn run_resume(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    set_paused(ctx, interaction, false).await;
+}
+
+async fn set_paused(ctx: &Context, interaction: &ApplicationCommandInteraction, paused: bool) {
+    let quizzes = {
+        let data_read = ctx.data.read().await;
+        data_read
+            .get::<BotRunningQuizzes>()
+            .expect("Expected BotRunningQuizzes")
+            .clone()
+    };
+    let quiz = match interaction.guild_id {
+        Some(guild_id) => quizzes.read().await.get(&guild_id).cloned(),
+        None => None,
+    };
+
+    let content = match quiz {
+        None => "no-quiz-running",
+        Some(quiz) => {
+            let quiz = quiz.write().await;
+            if quiz.host != interaction.user.id {
+                if paused {
+                    "pause-host-only"
+                } else {
+                    "resume-host-only"
+                }
+            } else if !quiz.set_paused(paused) {
+                if paused {
+                    "pause-already-paused"
+                } else {
+                    "resume-not-paused"
+                }
+            } else if paused {
+                "pause-done"
+            } else {
+                "resume-done"
+            }
+        }
+    };
+    let content = guild_language(ctx, interaction.guild_id)
+        .await
+        .text(content);
+
+    interaction
+        .create_interaction_response(ctx, |f| {
+            f.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|m| m.content(content))
+        })
+        .await
+        .unwrap();
+}
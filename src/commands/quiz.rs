@@ -1,8 +1,9 @@
 use rand::seq::SliceRandom;
 use regex::Regex;
+use rspotify::ClientCredsSpotify;
 use serenity::{
     async_trait,
-    builder::{CreateApplicationCommand, CreateButton, CreateEmbed, CreateInteractionResponse},
+    builder::{CreateApplicationCommand, CreateComponents, CreateEmbed},
     collector::MessageCollectorBuilder,
     futures::StreamExt,
     model::{
@@ -10,131 +11,158 @@ use serenity::{
             command,
             component::{ActionRowComponent::InputText, ButtonStyle, InputTextStyle},
             interaction::{
-                application_command::{ApplicationCommandInteraction, CommandDataOptionValue},
-                InteractionResponseType,
+                application_command::ApplicationCommandInteraction,
+                autocomplete::AutocompleteInteraction, InteractionResponseType,
             },
-            ChannelId, Guild, ReactionType, UserId,
+            AttachmentType, ChannelId, Guild, GuildId, ReactionType,
         },
         user::User,
     },
-    prelude::{Context, RwLock},
+    prelude::{Context, Mutex, RwLock},
     utils::MessageBuilder,
 };
-use songbird::{EventContext, EventHandler as VoiceEventHandler, TrackEvent};
-use sqlx::types::chrono;
+use songbird::{Call, EventContext, EventHandler as VoiceEventHandler, Songbird, TrackEvent};
+use sqlx::{types::chrono, SqlitePool};
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
-    sync::{atomic::Ordering, Arc},
+    sync::Arc,
     time::Duration,
 };
+use tokio::{
+    sync::{mpsc::UnboundedReceiver, watch},
+    task::{JoinError, JoinHandle},
+    time::Instant,
+};
 use tracing::info;
 
 use crate::{
+    audio::{
+        connection::watch_connection,
+        effects::{Effect, EffectPlayer, SoundEffects},
+        jingle::{load_jingle, DEFAULT_JOIN_JINGLE, DEFAULT_LEAVE_JINGLE},
+        loudness::{analyze_playlist, loudness_gain},
+        playback::{watch_playback, PlaybackEvent},
+        source::{preload, random_start_offset, to_input, UpcomingTrack},
+    },
+    commands::{
+        lobby::{run_late_join, run_lobby, LateJoin, LobbySettings},
+        quiz_config::{
+            AnswerTypes, QuizConfig, Thresholds, HINT_COUNTS, LOBBY_DURATIONS, PLAYER_COUNTS,
+            QUIZ_LENGTHS, ROUND_DURATIONS,
+        },
+        report::create_report_button,
+        round_status::{start_status_message, RoundClock, RoundStatus, StatusBoard},
+        skip::SKIP_MARKER,
+    },
     database::{
-        game::{insert_game, Game, Score},
+        game::{insert_game, Game, Round, RoundGuess, Score},
+        guild_settings::{read_guild_settings, GuildSettings},
         playlist::*,
-        song::{insert_songs, read_songs, Song},
+        song::{insert_songs, mark_song_failed, read_songs, Song},
     },
     spotify::spotify_api::*,
-    structs::CollectionResult,
+    structs::{CollectionResult, RunningQuiz},
+    util::locale::{add_localized_choice, localize_command, localize_option, Language},
+    util::podium::render_results,
     util::util::check_msg,
-    BotDatabase, BotParticipantCount, BotSkipVotes, BotSpotCred,
+    BotDatabase, BotRunningQuizzes, BotSpotCred,
 };
 use edit_distance::edit_distance;
 
-fn create_join_response(
-    response: &mut CreateInteractionResponse,
-    interaction_type: InteractionResponseType,
-    players: &HashSet<User>,
-) {
-    response
-        .kind(interaction_type)
-        .interaction_response_data(|r| {
-            r.content("Click the green button to join the quiz!")
-                .components(|c| {
-                    c.create_action_row(|row| {
-                        row.add_button({
-                            let mut b = CreateButton::default();
-                            b.custom_id("join_button");
-                            b.label("✅ Join");
-                            b.style(ButtonStyle::Success);
-                            b
-                        });
-
-                        row.add_button({
-                            let mut b = CreateButton::default();
-                            b.custom_id("leave_button");
-                            b.label("❌ Leave");
-                            b.style(ButtonStyle::Danger);
-                            b
-                        })
-                    })
-                })
-                .add_embed({
-                    let mut e = CreateEmbed::default();
-                    e.color(0xff7c1e);
-                    e.title("Join the quiz!");
-                    let player_string = players
-                        .iter()
-                        .map(|x| x.to_string().clone())
-                        .collect::<Vec<String>>()
-                        .join("\n");
-                    e.field("Participants", player_string, false);
-                    e
-                })
-        });
+// How long a track may take to start before the round timer runs anyway
+const STARTUP_GRACE: Duration = Duration::from_secs(10);
+const COUNTDOWN_TICKS: u32 = 5;
+const RECONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+const RECONNECT_RETRY: Duration = Duration::from_secs(5);
+// Discord allows 25 options per select menu, one of them is "Add new"
+const PLAYLIST_PAGE_SIZE: i64 = 24;
+const MAX_AUTOCOMPLETE_CHOICES: i64 = 25;
+
+fn describe_guess(guess: &Result<Guess, ()>, language: Language) -> String {
+    match guess {
+        Ok(Guess {
+            result: CollectionResult::Skip,
+            ..
+        }) => language.text("guess-skipped"),
+        Ok(guess) => language.text_with(
+            "guess-found",
+            &[
+                ("user", guess.user.to_string().into()),
+                (
+                    "seconds",
+                    format!("{:.1}", guess.elapsed.as_secs_f32()).into(),
+                ),
+            ],
+        ),
+        Err(_) => language.text("guess-nobody"),
+    }
 }
 
-async fn join_timer(
-    ctx: Context,
-    interaction: ApplicationCommandInteraction,
-    countdown_time: i8,
-    player_lock: Arc<RwLock<HashSet<User>>>,
-) {
-    let mut timer = tokio::time::interval(Duration::from_secs(1));
-    let mut count = countdown_time;
-    let mut message = interaction.get_interaction_response(&ctx).await.unwrap();
-    while count >= 0 {
-        timer.tick().await;
-        let players = player_lock.read().await;
-        message
-            .edit(&ctx, |r| {
-                r.content(format!("You have {} seconds to join!", count))
-                    .components(|c| {
-                        c.create_action_row(|row| {
-                            row.add_button({
-                                let mut b = CreateButton::default();
-                                b.custom_id("join_button");
-                                b.label("✅ Join");
-                                b.style(ButtonStyle::Success);
-                                b
-                            });
-
-                            row.add_button({
-                                let mut b = CreateButton::default();
-                                b.custom_id("leave_button");
-                                b.label("❌ Leave");
-                                b.style(ButtonStyle::Danger);
-                                b
-                            })
-                        })
-                    })
-                    .set_embed({
-                        let mut e = CreateEmbed::default();
-                        e.color(0xff7c1e);
-                        e.title("Join the quiz!");
-                        let player_string = players
-                            .iter()
-                            .map(|x| x.to_string().clone())
-                            .collect::<Vec<String>>()
-                            .join("\n");
-                        e.field("Participants", player_string, false);
-                        e
-                    })
-            })
-            .await
-            .unwrap();
-        count -= 1;
+fn create_round_embed(
+    round: u32,
+    song: &Song,
+    answers: AnswerTypes,
+    artist: &Result<Guess, ()>,
+    title: &Result<Guess, ()>,
+    language: Language,
+) -> CreateEmbed {
+    let mut e = CreateEmbed::default();
+    e.color(0xff7c1e);
+    e.title(&song.song_name);
+    e.url(song.get_url());
+    e.field(language.text("song-artist"), &song.artist_name, true);
+    if let Some(album_name) = &song.album_name {
+        e.field(language.text("song-album"), album_name, true);
+    }
+    if let Some(release_year) = song.release_year {
+        e.field(language.text("song-year"), release_year, true);
+    }
+    if let Some(album_cover_url) = &song.album_cover_url {
+        e.thumbnail(album_cover_url);
+    }
+    if answers.title() {
+        e.field(
+            language.text("song-title-found-by"),
+            describe_guess(title, language),
+            false,
+        );
+    }
+    if answers.artist() {
+        e.field(
+            language.text("song-artist-found-by"),
+            describe_guess(artist, language),
+            false,
+        );
+    }
+    e.footer(|f| f.text(language.text_with("round-number", &[("round", round.into())])));
+    e
+}
+
+// Strips everything from titles that players can't be expected to type
+struct TitleNormalizer {
+    parentheses: Regex,
+    dash: Regex,
+    special_character: Regex,
+    whitespace: Regex,
+}
+
+impl TitleNormalizer {
+    fn new() -> Self {
+        Self {
+            parentheses: Regex::new(r"\(.*\)").unwrap(),
+            dash: Regex::new(r"-.*").unwrap(),
+            special_character: Regex::new(r"[^0-9a-zA-Z\s]+").unwrap(),
+            whitespace: Regex::new(r"\s*").unwrap(),
+        }
+    }
+
+    fn normalize(&self, title: &str) -> String {
+        let mut title = self.dash.replace_all(title, "").to_string();
+        title = self.parentheses.replace_all(&title, "").to_string();
+        title = self.special_character.replace_all(&title, "").to_string();
+        title = self.whitespace.replace_all(&title, "").to_string();
+        title.to_lowercase()
     }
 }
 
@@ -142,22 +170,15 @@ async fn check_for_title(
     ctx: Context,
     channel_id: ChannelId,
     song: Song,
-    bot_user: UserId,
+    threshold: usize,
     player_lock: Arc<RwLock<HashSet<User>>>,
+    effects: Option<EffectPlayer>,
+    paused: watch::Receiver<bool>,
 ) -> Result<(User, CollectionResult), ()> {
-    let regex_parentheses: Regex = regex::Regex::new(r"\(.*\)").unwrap();
-    let regex_dash: Regex = regex::Regex::new(r"-.*").unwrap();
-    let regex_special_character: Regex = regex::Regex::new(r"[^0-9a-zA-Z\s]+").unwrap();
-    let regex_whitespace: Regex = regex::Regex::new(r"\s*").unwrap();
-
-    let mut song_title = regex_dash.replace_all(&song.song_name, "").to_string();
-    song_title = regex_parentheses.replace_all(&song_title, "").to_string();
-    song_title = regex_special_character
-        .replace_all(&song_title, "")
-        .to_string();
-    song_title = regex_whitespace.replace_all(&song_title, "").to_string();
-    song_title = song_title.to_lowercase();
+    let normalizer = TitleNormalizer::new();
+    let song_title = normalizer.normalize(&song.song_name);
 
+    let bot_user = ctx.cache.current_user_id();
     let players = {
         let players = player_lock.read().await;
         players.clone()
@@ -166,22 +187,15 @@ async fn check_for_title(
     let message_collector = MessageCollectorBuilder::new(&ctx)
         .channel_id(channel_id)
         .filter(move |m| {
-            if m.author.id == bot_user && m.content == "Skipping!" {
+            if m.author.id == bot_user && m.content.starts_with(SKIP_MARKER) {
                 return true;
             }
-            if !players.contains(&m.author) {
+            if *paused.borrow() || !players.contains(&m.author) {
                 return false;
             }
-            let mut guess = regex_dash.replace_all(&m.content, "").to_string();
-            guess = regex_parentheses.replace_all(&guess, "").to_string();
-            guess = regex_special_character.replace_all(&guess, "").to_string();
-            guess = regex_whitespace.replace_all(&guess, "").to_string();
-            guess = guess.to_lowercase();
-
-            is_title_correct(&guess, &song_title, 3)
+            is_title_correct(&normalizer.normalize(&m.content), &song_title, threshold)
         })
         .collect_limit(1)
-        .timeout(Duration::from_secs(29))
         .build();
     let collected: Vec<_> = message_collector
         .then(|msg| async move { msg })
@@ -197,6 +211,9 @@ async fn check_for_title(
         msg.react(&ctx, ReactionType::Unicode("🎶".to_string()))
             .await
             .unwrap();
+        if let Some(effects) = effects {
+            effects.play(Effect::Chime).await;
+        }
         Ok((msg.author.clone(), CollectionResult::Title))
     }
 }
@@ -205,9 +222,12 @@ async fn check_for_author(
     ctx: Context,
     channel_id: ChannelId,
     song: Song,
-    bot_user: UserId,
+    threshold: usize,
     player_lock: Arc<RwLock<HashSet<User>>>,
+    effects: Option<EffectPlayer>,
+    paused: watch::Receiver<bool>,
 ) -> Result<(User, CollectionResult), ()> {
+    let bot_user = ctx.cache.current_user_id();
     let players = {
         let players = player_lock.read().await;
         players.clone()
@@ -216,17 +236,16 @@ async fn check_for_author(
     let message_collector = MessageCollectorBuilder::new(&ctx)
         .channel_id(channel_id)
         .filter(move |m| {
-            if m.author.id == bot_user && m.content == "Skipping!" {
+            if m.author.id == bot_user && m.content.starts_with(SKIP_MARKER) {
                 return true;
             }
-            if !players.contains(&m.author) {
+            if *paused.borrow() || !players.contains(&m.author) {
                 return false;
             }
 
-            is_artist_correct(&m.content, &song, 3)
+            is_artist_correct(&m.content, &song, threshold)
         })
         .collect_limit(1)
-        .timeout(Duration::from_secs(29))
         .build();
     let collected: Vec<_> = message_collector
         .then(|msg| async move { msg })
@@ -242,207 +261,436 @@ async fn check_for_author(
         msg.react(&ctx, ReactionType::Unicode("🎙️".to_string()))
             .await
             .unwrap();
+        if let Some(effects) = effects {
+            effects.play(Effect::Chime).await;
+        }
         Ok((msg.author.clone(), CollectionResult::Artist))
     }
 }
 
+// Spectators guess along just for fun, their finds neither end the round nor score
+async fn track_spectators(
+    ctx: Context,
+    channel_id: ChannelId,
+    song: Song,
+    spectators: HashSet<User>,
+    thresholds: Thresholds,
+    mut round: watch::Receiver<RoundStatus>,
+) -> Vec<(User, CollectionResult)> {
+    let mut found = Vec::<(User, CollectionResult)>::new();
+    if spectators.is_empty() {
+        return found;
+    }
+    let normalizer = TitleNormalizer::new();
+    let song_title = normalizer.normalize(&song.song_name);
+
+    let mut message_collector = MessageCollectorBuilder::new(&ctx)
+        .channel_id(channel_id)
+        .filter(move |m| spectators.contains(&m.author))
+        .build();
+    loop {
+        let msg = tokio::select! {
+            msg = message_collector.next() => match msg {
+                Some(msg) => msg,
+                None => break,
+            },
+            _ = async {
+                let _ = round.wait_for(|status| matches!(status.clock, RoundClock::Over)).await;
+            } => break,
+        };
+        let part = if is_title_correct(
+            &normalizer.normalize(&msg.content),
+            &song_title,
+            thresholds.title,
+        ) {
+            CollectionResult::Title
+        } else if is_artist_correct(&msg.content, &song, thresholds.artist) {
+            CollectionResult::Artist
+        } else {
+            continue;
+        };
+        if found
+            .iter()
+            .any(|(user, found_part)| user == &msg.author && *found_part == part)
+        {
+            continue;
+        }
+        let _ = msg
+            .react(&ctx, ReactionType::Unicode("👀".to_string()))
+            .await;
+        found.push((msg.author.clone(), part));
+    }
+    found
+}
+
+// Everything besides the guesses that can end a round
+struct RoundSignals {
+    playback: UnboundedReceiver<PlaybackEvent>,
+    connection: watch::Receiver<bool>,
+    paused: watch::Receiver<bool>,
+    length: Duration,
+    // Parts that aren't asked count as not found right away
+    answers: AnswerTypes,
+    thresholds: Thresholds,
+    status: watch::Sender<RoundStatus>,
+}
+
+struct Guess {
+    user: User,
+    result: CollectionResult,
+    // Time into the round, pauses not included
+    elapsed: Duration,
+}
+
+fn to_guess(
+    result: Result<Result<(User, CollectionResult), ()>, JoinError>,
+    round_length: Duration,
+    deadline: Instant,
+) -> Result<Guess, ()> {
+    let (user, result) = result.unwrap_or(Err(()))?;
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    Ok(Guess {
+        user,
+        result,
+        elapsed: round_length.saturating_sub(remaining),
+    })
+}
+
+// Skips don't count as finding a part
+fn found_by(guess: &Result<Guess, ()>) -> Option<(User, Duration)> {
+    match guess {
+        Ok(Guess {
+            result: CollectionResult::Skip,
+            ..
+        })
+        | Err(_) => None,
+        Ok(guess) => Some((guess.user.clone(), guess.elapsed)),
+    }
+}
+
+// Ticks once a second during the last seconds before the deadline
+fn start_countdown(effects: EffectPlayer, deadline: Instant) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let ticks = COUNTDOWN_TICKS.min(remaining.as_secs() as u32);
+        tokio::time::sleep_until(deadline - Duration::from_secs(ticks as u64)).await;
+        let mut timer = tokio::time::interval(Duration::from_secs(1));
+        for _ in 0..ticks {
+            timer.tick().await;
+            effects.play(Effect::Tick).await;
+        }
+    })
+}
+
 async fn get_winners(
     ctx: &Context,
     channel_id: ChannelId,
     song: Song,
     players: Arc<RwLock<HashSet<User>>>,
+    spectators: HashSet<User>,
+    effects: Option<EffectPlayer>,
+    signals: RoundSignals,
 ) -> (
-    Result<(User, CollectionResult), ()>,
-    Result<(User, CollectionResult), ()>,
+    Result<Guess, ()>,
+    Result<Guess, ()>,
+    Vec<(User, CollectionResult)>,
 ) {
-    let me = ctx.cache.current_user();
-    let artist_handle = tokio::spawn(check_for_author(
+    let RoundSignals {
+        mut playback,
+        mut connection,
+        mut paused,
+        length: round_length,
+        answers,
+        thresholds,
+        status,
+    } = signals;
+    let spectator_handle = tokio::spawn(track_spectators(
         ctx.clone(),
         channel_id,
         song.clone(),
-        me.id,
-        players.clone(),
+        spectators,
+        thresholds,
+        status.subscribe(),
     ));
-    let title_handle = tokio::spawn(check_for_title(
-        ctx.clone(),
-        channel_id,
-        song.clone(),
-        me.id,
-        players,
-    ));
-    while !artist_handle.is_finished() && !title_handle.is_finished() {
-        tokio::time::sleep(Duration::from_secs(1)).await;
-    }
-    let author = artist_handle.await.unwrap();
-    let title = title_handle.await.unwrap();
-    (author, title)
-}
+    let mut artist_handle = if answers.artist() {
+        tokio::spawn(check_for_author(
+            ctx.clone(),
+            channel_id,
+            song.clone(),
+            thresholds.artist,
+            players.clone(),
+            effects.clone(),
+            paused.clone(),
+        ))
+    } else {
+        tokio::spawn(async { Err(()) })
+    };
+    let mut title_handle = if answers.title() {
+        tokio::spawn(check_for_title(
+            ctx.clone(),
+            channel_id,
+            song.clone(),
+            thresholds.title,
+            players,
+            effects.clone(),
+            paused.clone(),
+        ))
+    } else {
+        tokio::spawn(async { Err(()) })
+    };
 
-pub fn register_quiz(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
-    command
-        .name("quiz")
-        .description("Hier entsteht großes")
-        .create_option(|option| {
-            option
-                .name("quiz_length")
-                .description("Quiz length")
-                .description_localized("de", "Quizlaenge")
-                .kind(command::CommandOptionType::Integer)
-                .min_int_value(1)
-                .required(true)
-        })
-}
+    // The clock only starts once the audio is actually playing
+    let mut clip_ended = false;
+    let _ = tokio::time::timeout(STARTUP_GRACE, async {
+        if !matches!(playback.recv().await, Some(PlaybackEvent::Started)) {
+            clip_ended = true;
+        }
+    })
+    .await;
+    let mut deadline = Instant::now() + round_length;
 
-pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction) {
-    let start_time = chrono::Utc::now().naive_utc();
-    let quiz_length_option = interaction
-        .data
-        .options
-        .get(0)
-        .expect("Expected an Option")
-        .resolved
-        .as_ref()
-        .expect("Expected int Object");
-
-    let quiz_length = match quiz_length_option {
-        CommandDataOptionValue::Integer(x) => *x as u8,
-        _ => {
-            check_msg(
-                interaction
-                    .channel_id
-                    .say(ctx, "Quiz length is not an integer!")
-                    .await,
-            );
-            return;
+    // While paused the deadline is pushed back by the time spent waiting
+    let mut paused_since = None;
+    let mut countdown = None;
+    if *paused.borrow() {
+        paused_since = Some(Instant::now());
+        status.send_modify(|s| s.clock = RoundClock::Paused(round_length));
+    } else {
+        countdown = effects
+            .clone()
+            .map(|effects| start_countdown(effects, deadline));
+        status.send_modify(|s| s.clock = RoundClock::Running(deadline));
+    }
+    let mut author = None;
+    let mut title = None;
+    while !clip_ended && (author.is_none() || title.is_none()) {
+        tokio::select! {
+            result = &mut artist_handle, if author.is_none() => {
+                let guess = to_guess(result, round_length, deadline);
+                status.send_modify(|s| s.artist = found_by(&guess));
+                author = Some(guess);
+            }
+            result = &mut title_handle, if title.is_none() => {
+                let guess = to_guess(result, round_length, deadline);
+                status.send_modify(|s| s.title = found_by(&guess));
+                title = Some(guess);
+            }
+            _ = tokio::time::sleep_until(deadline), if paused_since.is_none() => break,
+            changed = paused.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                let is_paused = *paused.borrow();
+                if is_paused && paused_since.is_none() {
+                    paused_since = Some(Instant::now());
+                    if let Some(countdown) = countdown.take() {
+                        countdown.abort();
+                    }
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    status.send_modify(|s| s.clock = RoundClock::Paused(remaining));
+                } else if !is_paused {
+                    if let Some(since) = paused_since.take() {
+                        deadline += since.elapsed();
+                        countdown = effects
+                            .clone()
+                            .map(|effects| start_countdown(effects, deadline));
+                        status.send_modify(|s| s.clock = RoundClock::Running(deadline));
+                    }
+                }
+            }
+            event = playback.recv() => {
+                if matches!(event, Some(PlaybackEvent::Ended) | None) {
+                    clip_ended = true;
+                }
+            }
+            changed = connection.changed() => {
+                if changed.is_err() || !*connection.borrow() {
+                    break;
+                }
+            }
         }
-    };
-    join_channel(&ctx, &interaction).await.unwrap();
-    let channel = interaction.channel_id;
-    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
-    let spotify = { ctx.data.read().await.get::<BotSpotCred>().unwrap().clone() };
+    }
+    if let Some(countdown) = countdown {
+        countdown.abort();
+    }
+    status.send_modify(|s| s.clock = RoundClock::Over);
 
-    let playlists = match read_playlists(&database).await {
-        Ok(result) => result,
-        _ => {
-            check_msg(
-                channel
-                    .say(ctx, "Reading Playlists from Database failed!")
-                    .await,
-            );
-            return;
+    // Parts nobody found in time count as timed out
+    let author = match author {
+        Some(author) => author,
+        None => {
+            artist_handle.abort();
+            to_guess(artist_handle.await, round_length, deadline)
+        }
+    };
+    let title = match title {
+        Some(title) => title,
+        None => {
+            title_handle.abort();
+            to_guess(title_handle.await, round_length, deadline)
         }
     };
+    let spectator_finds = spectator_handle.await.unwrap_or_default();
+    (author, title, spectator_finds)
+}
 
-    let players = Arc::new(RwLock::new(HashSet::<User>::new()));
-    let countdown_time: i8 = 10;
-    {
-        let p = players.read().await;
-        let _asd = interaction
-            .create_interaction_response(&ctx.http, |f| {
-                create_join_response(f, InteractionResponseType::ChannelMessageWithSource, &p);
+// The playlist option holds the id of an autocompleted playlist,
+// anything else typed into it is looked up by name
+pub async fn find_playlist(database: &SqlitePool, guild_id: i64, value: &str) -> Option<Playlist> {
+    if let Ok(id) = value.parse::<i64>() {
+        if let Ok(Some(playlist)) = read_playlist(database, id, guild_id).await {
+            return Some(playlist);
+        }
+    }
+    let mut matches = search_playlists(database, guild_id, value, 2).await.ok()?;
+    if matches.len() == 1 {
+        matches.pop()
+    } else {
+        None
+    }
+}
+
+fn create_playlist_components(
+    playlists: &[Playlist],
+    page: i64,
+    pages: i64,
+    language: Language,
+) -> CreateComponents {
+    let mut c = CreateComponents::default();
+    c.create_action_row(|row| {
+        row.create_select_menu(|menu| {
+            menu.custom_id("playlist_select");
+            menu.placeholder(language.text("playlist-placeholder"));
+            menu.options(|f| {
+                f.create_option(|o| o.label(language.text("playlist-add-new")).value("Add new"));
+                for playlist in playlists {
+                    f.create_option(|o| o.label(&playlist.name).value(&playlist.id));
+                }
                 f
             })
-            .await;
+        })
+    });
+    if pages > 1 {
+        c.create_action_row(|row| {
+            row.create_button(|b| {
+                b.custom_id("playlist_previous");
+                b.label("◀");
+                b.style(ButtonStyle::Secondary);
+                b.disabled(page == 0)
+            });
+            row.create_button(|b| {
+                b.custom_id("playlist_next");
+                b.label("▶");
+                b.style(ButtonStyle::Secondary);
+                b.disabled(page + 1 >= pages)
+            })
+        });
+    }
+    c
+}
+
+fn playlist_page_text(page: i64, pages: i64, language: Language) -> String {
+    if pages > 1 {
+        language.text_with(
+            "playlist-select-page",
+            &[("page", (page + 1).into()), ("pages", pages.into())],
+        )
+    } else {
+        language.text("playlist-select")
     }
+}
 
-    let resp = interaction.get_interaction_response(ctx).await;
-    let message = match resp {
-        Ok(resp) => resp,
+// Lets the host pick a playlist page by page or add a new one
+async fn select_playlist(
+    ctx: &Context,
+    interaction: &ApplicationCommandInteraction,
+    database: &SqlitePool,
+    spotify: &Arc<ClientCredsSpotify>,
+    language: Language,
+) -> Option<i64> {
+    let guild_id = interaction.guild_id?.0 as i64;
+    let pages = match count_playlists(database, guild_id).await {
+        Ok(count) => ((count + PLAYLIST_PAGE_SIZE - 1) / PLAYLIST_PAGE_SIZE).max(1),
         _ => {
-            check_msg(channel.say(ctx, "Something went wrong here!").await);
-            return;
+            check_msg(
+                interaction
+                    .channel_id
+                    .say(ctx, language.text("playlist-read-failed"))
+                    .await,
+            );
+            return None;
         }
     };
-    tokio::spawn(join_timer(
-        ctx.clone(),
-        interaction.clone(),
-        countdown_time,
-        Arc::clone(&players),
-    ));
-
-    let interactions = message.await_component_interactions(ctx);
-    let mut response_collector = interactions
-        .timeout(Duration::from_secs((countdown_time + 1) as u64))
-        .build();
-    while let Some(event) = response_collector.next().await {
-        match event.data.custom_id.as_str() {
-            "join_button" => {
-                {
-                    let mut p = players.write().await;
-                    p.insert(event.user.clone());
-                    info!("{:?}", p);
-                }
-                let _e = event
-                    .create_interaction_response(ctx, |resp| {
-                        resp.kind(InteractionResponseType::UpdateMessage)
-                    })
-                    .await;
-            }
-            "leave_button" => {
-                {
-                    let mut p = players.write().await;
-                    p.remove(&event.user);
-                    info!("{:?}", p);
-                }
-                let _e = event
-                    .create_interaction_response(ctx, |resp| {
-                        resp.kind(InteractionResponseType::UpdateMessage)
-                    })
-                    .await;
-            }
-
-            _ => {}
-        }
-    }
+    let mut page = 0;
+    let mut playlists = read_playlists_page(database, guild_id, 0, PLAYLIST_PAGE_SIZE)
+        .await
+        .unwrap_or_default();
     let playlist_message = interaction
         .create_followup_message(ctx, |f| {
-            f.content("Please select a playlist!");
-            f.ephemeral(true).components(|c| {
-                c.create_action_row(|row| {
-                    row.create_select_menu(|menu| {
-                        menu.custom_id("playlist_select");
-                        menu.placeholder("Select a playlist");
-                        menu.options(|f| {
-                            f.create_option(|o| o.label("Add new").value("Add new"));
-                            for playlist in &playlists {
-                                f.create_option(|o| o.label(&playlist.name).value(&playlist.id));
-                            }
-                            f
-                        })
-                    })
-                })
-            })
+            f.content(playlist_page_text(page, pages, language));
+            f.ephemeral(true).set_components(create_playlist_components(
+                &playlists, page, pages, language,
+            ))
         })
         .await
         .unwrap();
-    let playlist_interaction = match playlist_message
-        .await_component_interaction(&ctx)
-        .timeout(Duration::from_secs(60 * 3))
-        .await
-    {
-        Some(x) => x,
-        None => {
-            playlist_message.reply(&ctx, "Timed out").await.unwrap();
-            return;
+    let playlist_interaction = loop {
+        let event = match playlist_message
+            .await_component_interaction(&ctx)
+            .timeout(Duration::from_secs(60 * 3))
+            .await
+        {
+            Some(x) => x,
+            None => {
+                playlist_message
+                    .reply(&ctx, language.text("playlist-timed-out"))
+                    .await
+                    .unwrap();
+                return None;
+            }
+        };
+        match event.data.custom_id.as_str() {
+            "playlist_previous" => page = (page - 1).max(0),
+            "playlist_next" => page = (page + 1).min(pages - 1),
+            _ => break event,
         }
+        playlists = read_playlists_page(
+            database,
+            guild_id,
+            page * PLAYLIST_PAGE_SIZE,
+            PLAYLIST_PAGE_SIZE,
+        )
+        .await
+        .unwrap_or_default();
+        let _ = event
+            .create_interaction_response(&ctx, |r| {
+                r.kind(InteractionResponseType::UpdateMessage)
+                    .interaction_response_data(|d| {
+                        d.content(playlist_page_text(page, pages, language))
+                            .set_components(create_playlist_components(
+                                &playlists, page, pages, language,
+                            ))
+                    })
+            })
+            .await;
     };
     let interaction_result = &playlist_interaction.data.values[0];
-    let selected_playlist = if interaction_result == "Add new" {
+    if interaction_result == "Add new" {
         playlist_interaction
             .create_interaction_response(&ctx, |r| {
                 r.kind(InteractionResponseType::Modal)
                     .interaction_response_data(|d| {
-                        d.title("Add a new Playlist");
+                        d.title(language.text("playlist-modal-title"));
                         d.custom_id("playlist_modal");
-                        d.content("Please enter a Spotify-Playlist URL")
+                        d.content(language.text("playlist-modal-content"))
                             .components(|c| {
                                 c.create_action_row(|row| {
                                     row.create_input_text(|f| {
                                         f.custom_id("playlist_url");
-                                        f.placeholder("Enter a Spotify-Playlist URL");
+                                        f.placeholder(language.text("playlist-modal-placeholder"));
                                         f.style(InputTextStyle::Short);
                                         f.min_length(10);
-                                        f.label("Playlist URL")
+                                        f.label(language.text("playlist-modal-label"))
                                     })
                                 })
                             })
@@ -458,10 +706,10 @@ pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction
             Some(x) => x,
             None => {
                 playlist_message
-                    .reply(&ctx, "You took too long to select a playlist")
+                    .reply(&ctx, language.text("playlist-modal-timed-out"))
                     .await
                     .unwrap();
-                return;
+                return None;
             }
         };
         let modal_result = match &modal_interaction.data.components[0].components[0] {
@@ -474,33 +722,39 @@ pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction
                 .create_interaction_response(&ctx, |r| {
                     r.kind(InteractionResponseType::ChannelMessageWithSource)
                         .interaction_response_data(|f| {
-                            f.content("Please provide a valid Spotify-Playlist-Url")
+                            f.content(language.text("playlist-invalid-url"))
                         })
                 })
                 .await
                 .unwrap();
-            return;
+            return None;
         }
-        let mut modal_playlist = get_playlist_data(&spotify, modal_result.clone())
+        let mut modal_playlist = get_playlist_data(spotify, modal_result.clone())
             .await
             .unwrap();
         modal_interaction
             .create_interaction_response(&ctx, |r| {
                 r.kind(InteractionResponseType::ChannelMessageWithSource)
                     .interaction_response_data(|f| {
-                        f.ephemeral(true)
-                            .content(format!("Trying to add {:?} ", modal_result.clone()))
+                        f.ephemeral(true).content(
+                            language.text_with(
+                                "playlist-adding",
+                                &[("url", modal_result.clone().into())],
+                            ),
+                        )
                     })
             })
             .await
             .unwrap();
 
         // TODO: Send updating message with progress
-        let songs = get_tracks(&spotify, modal_playlist.spotify_id.clone())
+        let songs = get_tracks(spotify, modal_playlist.spotify_id.clone())
             .await
             .unwrap();
         let mut tx = database.begin().await.unwrap();
-        insert_playlist(&mut tx, &modal_playlist).await.unwrap();
+        insert_playlist(&mut tx, &modal_playlist, guild_id)
+            .await
+            .unwrap();
         modal_playlist.id = read_playlist_id(&mut tx, &modal_playlist.spotify_id)
             .await
             .unwrap();
@@ -508,8 +762,9 @@ pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction
             .await
             .unwrap();
         tx.commit().await.unwrap();
+        tokio::spawn(analyze_playlist(database.clone(), modal_playlist.id));
 
-        modal_playlist.id
+        Some(modal_playlist.id)
     } else {
         let result = &playlist_interaction.data.values[0];
         let playlist_id = result.parse::<i64>().unwrap();
@@ -522,33 +777,251 @@ pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction
             .create_interaction_response(&ctx, |r| {
                 r.kind(InteractionResponseType::ChannelMessageWithSource)
                     .interaction_response_data(|f| {
-                        f.content(format!(
-                            "{} chose:\n{}",
-                            interaction.user.to_string(),
-                            playlist_url
+                        f.content(language.text_with(
+                            "playlist-chosen",
+                            &[
+                                ("user", interaction.user.to_string().into()),
+                                ("url", playlist_url.into()),
+                            ],
                         ))
                     })
             })
             .await
             .unwrap();
-        playlist_id
+        Some(playlist_id)
+    }
+}
+
+pub fn register_quiz(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("quiz"), "quiz-description")
+        .create_option(|option| {
+            localize_option(option.name("quiz_length"), "quiz-option-quiz_length")
+                .kind(command::CommandOptionType::Integer)
+                .min_int_value(*QUIZ_LENGTHS.start())
+                .max_int_value(*QUIZ_LENGTHS.end())
+                .required(true)
+        })
+        .create_option(|option| {
+            localize_option(option.name("playlist"), "quiz-option-playlist")
+                .kind(command::CommandOptionType::String)
+                .set_autocomplete(true)
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("mode"), "quiz-option-mode")
+                .kind(command::CommandOptionType::String);
+            for mode in ["classic", "casual", "lightning", "hardcore"] {
+                add_localized_choice(option, &format!("quiz-mode-{}", mode), mode);
+            }
+            option.required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("round_duration"), "quiz-option-round_duration")
+                .kind(command::CommandOptionType::Integer)
+                .min_int_value(*ROUND_DURATIONS.start())
+                .max_int_value(*ROUND_DURATIONS.end())
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("scoring"), "quiz-option-scoring")
+                .kind(command::CommandOptionType::String);
+            for scoring in ["fixed", "speed"] {
+                add_localized_choice(option, &format!("quiz-scoring-{}", scoring), scoring);
+            }
+            option.required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("answers"), "quiz-option-answers")
+                .kind(command::CommandOptionType::String);
+            for answers in ["both", "artist", "title"] {
+                add_localized_choice(option, &format!("quiz-answers-{}", answers), answers);
+            }
+            option.required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("hints"), "quiz-option-hints")
+                .kind(command::CommandOptionType::Integer)
+                .min_int_value(*HINT_COUNTS.start())
+                .max_int_value(*HINT_COUNTS.end())
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("lobby_duration"), "quiz-option-lobby_duration")
+                .kind(command::CommandOptionType::Integer)
+                .min_int_value(*LOBBY_DURATIONS.start())
+                .max_int_value(*LOBBY_DURATIONS.end())
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("min_players"), "quiz-option-min_players")
+                .kind(command::CommandOptionType::Integer)
+                .min_int_value(*PLAYER_COUNTS.start())
+                .max_int_value(*PLAYER_COUNTS.end())
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("late_join"), "quiz-option-late_join")
+                .kind(command::CommandOptionType::String);
+            for late_join in ["zero", "catch_up", "off"] {
+                add_localized_choice(option, &format!("quiz-late-join-{}", late_join), late_join);
+            }
+            option.required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("random_start"), "quiz-option-random_start")
+                .kind(command::CommandOptionType::Boolean)
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("distortion"), "quiz-option-distortion")
+                .kind(command::CommandOptionType::String);
+            for distortion in [
+                "off",
+                "reversed",
+                "pitch_up",
+                "pitch_down",
+                "sped_up",
+                "muffled",
+                "random",
+            ] {
+                add_localized_choice(
+                    option,
+                    &format!("quiz-distortion-{}", distortion),
+                    distortion,
+                );
+            }
+            option.required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("sound_effects"), "quiz-option-sound_effects")
+                .kind(command::CommandOptionType::Boolean)
+                .required(false)
+        })
+}
+
+// Suggests the playlists whose name contains what was typed so far
+pub async fn autocomplete_playlist(ctx: &Context, autocomplete: &AutocompleteInteraction) {
+    let query = autocomplete
+        .data
+        .options
+        .iter()
+        .find(|option| option.focused)
+        .and_then(|option| option.value.as_ref())
+        .and_then(|value| value.as_str())
+        .unwrap_or("");
+    let guild_id = match autocomplete.guild_id {
+        Some(guild_id) => guild_id.0 as i64,
+        None => return,
     };
-    info!("Selected playlist: {}", selected_playlist);
+    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
+    let playlists = search_playlists(&database, guild_id, query, MAX_AUTOCOMPLETE_CHOICES)
+        .await
+        .unwrap_or_default();
+    if let Err(why) = autocomplete
+        .create_autocomplete_response(&ctx.http, |r| {
+            for playlist in &playlists {
+                // Choice names can't be longer than 100 characters
+                let name: String = playlist.name.chars().take(100).collect();
+                r.add_string_choice(name, playlist.id);
+            }
+            r
+        })
+        .await
+    {
+        info!("Err answering playlist autocomplete: {:?}", why);
+    }
+}
 
-    // Store number of participants for skip command
-    let participant_lock = {
-        let data_read = ctx.data.read().await;
-        data_read
-            .get::<BotParticipantCount>()
-            .expect("Expected BotParticipantCount")
-            .clone()
+// Unregisters the quiz however run_quiz exits, early returns and panics included
+struct RunningQuizGuard {
+    running_quizzes: Arc<RwLock<HashMap<GuildId, Arc<RwLock<RunningQuiz>>>>>,
+    guild_id: GuildId,
+    running_quiz: Arc<RwLock<RunningQuiz>>,
+}
+
+impl Drop for RunningQuizGuard {
+    fn drop(&mut self) {
+        let running_quizzes = self.running_quizzes.clone();
+        let guild_id = self.guild_id;
+        let running_quiz = self.running_quiz.clone();
+        tokio::spawn(async move {
+            let mut running_quizzes = running_quizzes.write().await;
+            // A quiz started since then must not be removed
+            if running_quizzes
+                .get(&guild_id)
+                .is_some_and(|current| Arc::ptr_eq(current, &running_quiz))
+            {
+                running_quizzes.remove(&guild_id);
+            }
+        });
+    }
+}
+
+pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    let start_time = chrono::Utc::now().naive_utc();
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id,
+        None => return,
     };
-    let player_count = {
-        let p = players.read().await;
-        p.len()
+    let settings = read_settings(ctx, guild_id).await;
+    let config = match QuizConfig::from_options(&interaction.data.options, &settings) {
+        Ok(config) => config,
+        Err(why) => {
+            let _ = interaction
+                .create_interaction_response(ctx, |r| {
+                    r.kind(InteractionResponseType::ChannelMessageWithSource)
+                        .interaction_response_data(|d| d.ephemeral(true).content(why))
+                })
+                .await;
+            return;
+        }
     };
+    let language = config.language;
+    let voice_channel = join_channel(&ctx, &interaction).await.unwrap();
+    let channel = interaction.channel_id;
+    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
+    let spotify = { ctx.data.read().await.get::<BotSpotCred>().unwrap().clone() };
 
-    participant_lock.store(player_count as u8, Ordering::SeqCst);
+    let lobby_settings = LobbySettings {
+        duration: config.lobby_duration,
+        min_players: config.min_players,
+        language,
+    };
+    let players = match run_lobby(ctx, interaction, lobby_settings).await {
+        Some(players) => Arc::new(RwLock::new(players)),
+        None => {
+            let _ = leave_channel(ctx, interaction).await;
+            return;
+        }
+    };
+    let preselected = match &config.playlist {
+        Some(value) => find_playlist(&database, guild_id.0 as i64, value).await,
+        None => None,
+    };
+    let selected_playlist = match preselected {
+        Some(playlist) => {
+            check_msg(
+                channel
+                    .say(
+                        ctx,
+                        language.text_with(
+                            "playlist-chosen",
+                            &[
+                                ("user", interaction.user.to_string().into()),
+                                ("url", playlist.get_url().into()),
+                            ],
+                        ),
+                    )
+                    .await,
+            );
+            playlist.id
+        }
+        None => match select_playlist(ctx, interaction, &database, &spotify, language).await {
+            Some(playlist_id) => playlist_id,
+            None => return,
+        },
+    };
+    info!("Selected playlist: {}", selected_playlist);
 
     let mut tracks = match read_songs(&database, selected_playlist).await {
         Ok(t) => t,
@@ -556,7 +1029,7 @@ pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction
             check_msg(
                 interaction
                     .channel_id
-                    .say(&ctx, "Failed to fetch Songs from DB!")
+                    .say(&ctx, language.text("songs-read-failed"))
                     .await,
             );
             return;
@@ -567,121 +1040,369 @@ pub async fn run_quiz(ctx: &Context, interaction: &ApplicationCommandInteraction
 
     let mut round_counter: u32 = 1;
 
-    let guild_id = interaction.guild_id.unwrap();
-
     let manager = songbird::get(ctx)
         .await
         .expect("Songbird Voice client placed in at initialisation.")
         .clone();
 
-    let mut scores = HashMap::<User, u8>::new();
+    let running_quizzes = {
+        let data_read = ctx.data.read().await;
+        data_read
+            .get::<BotRunningQuizzes>()
+            .expect("Expected BotRunningQuizzes")
+            .clone()
+    };
+    let running_quiz = Arc::new(RwLock::new(RunningQuiz::new(
+        interaction.user.id,
+        settings.volume as f32,
+        settings.skip_ratio as f32,
+    )));
+    running_quizzes
+        .write()
+        .await
+        .insert(guild_id, running_quiz.clone());
+    let _running_quiz_guard = RunningQuizGuard {
+        running_quizzes: running_quizzes.clone(),
+        guild_id,
+        running_quiz: running_quiz.clone(),
+    };
+    let mut paused = running_quiz.read().await.paused.subscribe();
+
+    let sound_effects = if config.sound_effects {
+        match SoundEffects::load().await {
+            Ok(effects) => Some(Arc::new(effects)),
+            Err(why) => {
+                info!("Err loading sound effects: {:?}", why);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut rounds = Vec::<Round>::new();
+    let mut guesses = Vec::<RoundGuess>::new();
+    let mut scores = HashMap::<User, u32>::new();
+    // Points per player for every round played, for the results image
+    let mut round_history = Vec::<HashMap<User, u32>>::new();
     {
         let players = players.read().await;
         for player in players.iter() {
             scores.insert(player.clone(), 0);
         }
     }
+    let spectators = Arc::new(RwLock::new(HashSet::<User>::new()));
+    let mut spectator_scores = HashMap::<User, u32>::new();
+    let late_join_handle = tokio::spawn(run_late_join(
+        ctx.clone(),
+        channel,
+        config.late_join,
+        players.clone(),
+        spectators.clone(),
+        language,
+    ));
 
-    for track in tracks.into_iter().take(quiz_length as usize) {
-        // Reset skip counter
-        let counter_lock = {
-            let data_read = ctx.data.read().await;
-            data_read
-                .get::<BotSkipVotes>()
-                .expect("Expected SkipVotes")
-                .clone()
+    let mut connection = match manager.get(guild_id) {
+        Some(handler_lock) => watch_connection(&handler_lock).await,
+        None => watch::channel(false).1,
+    };
+
+    // Each track is preloaded while the previous round is still running.
+    // Tracks that fail to load are replaced by the next one in the shuffled list.
+    let mut upcoming = tracks
+        .into_iter()
+        .map(|track| preload(track, config.distortion.pick(), database.clone()));
+    let mut next_track = None;
+    while round_counter <= config.quiz_length {
+        let UpcomingTrack {
+            song: track,
+            distortion,
+            source: pending_source,
+        } = match next_track.take().or_else(|| upcoming.next()) {
+            Some(next) => next,
+            None => {
+                check_msg(
+                    channel
+                        .say(&ctx.http, language.text("songs-exhausted"))
+                        .await,
+                );
+                break;
+            }
         };
-        {
-            let mut counter = counter_lock.write().await;
-            counter.clear();
+        if round_counter < config.quiz_length {
+            next_track = upcoming.next();
         }
+        // Reset skip counter
+        running_quiz.write().await.skip_votes.clear();
 
-        channel
-            .say(&ctx.http, format!("Round {}", round_counter))
-            .await
-            .unwrap();
-        if let Some(handler_lock) = manager.get(guild_id) {
-            let mut handler = handler_lock.lock().await;
+        let prepared = pending_source.await.unwrap().and_then(|prepared| {
+            to_input(&prepared.memory).map(|source| (source, prepared.loudness))
+        });
+        let (source, loudness) = match prepared {
+            Ok(prepared) => prepared,
+            Err(why) => {
+                info!("Err starting source for {}: {:?}", track.preview_url, why);
+                if let Err(why) = mark_song_failed(&database, track.id).await {
+                    info!("Failed to mark song {} as broken: {:?}", track.id, why);
+                }
 
-            let source = match songbird::ytdl(&track.preview_url).await {
-                Ok(source) => source,
-                Err(why) => {
-                    info!("Err starting source: {:?}", why);
+                check_msg(
+                    channel
+                        .say(
+                            &ctx.http,
+                            language.text_with(
+                                "song-unplayable",
+                                &[
+                                    ("title", track.song_name.clone().into()),
+                                    ("artist", track.artist_name.clone().into()),
+                                    ("error", why.to_string().into()),
+                                ],
+                            ),
+                        )
+                        .await,
+                );
+                continue;
+            }
+        };
 
-                    check_msg(
-                        interaction
-                            .channel_id
-                            .say(&ctx.http, "Error sourcing ffmpeg")
-                            .await,
-                    );
+        let tempo = distortion.map_or(1.0, |distortion| distortion.tempo());
+        let clip_length = source.metadata.duration.map(|d| d.div_f32(tempo));
+        let start_offset = if config.random_start {
+            random_start_offset(clip_length)
+        } else {
+            Duration::ZERO
+        };
+        // Rounds never outlast the clip itself
+        let round_length = match clip_length {
+            Some(clip_length) => config
+                .round_duration
+                .min(clip_length.saturating_sub(start_offset)),
+            None => config.round_duration,
+        };
+        let points = distortion.map_or(1, |distortion| distortion.score_multiplier());
 
-                    return;
+        let round_title = match distortion {
+            Some(distortion) => language.text_with(
+                "round-distorted",
+                &[
+                    ("round", round_counter.into()),
+                    ("distortion", distortion.label(language).into()),
+                    ("points", points.into()),
+                ],
+            ),
+            None => language.text_with("round-number", &[("round", round_counter.into())]),
+        };
+        // Hold the next round back while the quiz is paused
+        let _ = paused.wait_for(|paused| !*paused).await;
+        let handler_lock = match manager.get(guild_id) {
+            Some(handler_lock) if *connection.borrow() => handler_lock,
+            _ => match await_reconnect(ctx, &manager, guild_id, voice_channel, channel, language)
+                .await
+            {
+                Some((handler_lock, reconnected)) => {
+                    connection = reconnected;
+                    handler_lock
                 }
+                None => break,
+            },
+        };
+
+        // Players who joined late are scored from their first round on
+        {
+            let players = players.read().await;
+            let start_score = match config.late_join {
+                LateJoin::CatchUp => scores.values().min().copied().unwrap_or(0),
+                _ => 0,
             };
-            handler.play_source(source).set_volume(0.5).unwrap();
-            info!("Playing: {} by {}", track.song_name, track.artist_name);
-            let (artist_result, title_result) = {
-                let res = get_winners(
-                    &ctx,
-                    interaction.channel_id.clone(),
-                    track.clone(),
-                    players.clone(),
-                )
-                .await;
-                (res.0, res.1)
-            };
-            match artist_result {
-                Ok(author) => {
-                    let (author_id, res) = author;
-                    match res {
-                        CollectionResult::Artist => {
-                            scores.insert(author_id.clone(), scores.get(&author_id).unwrap() + 1);
-                        }
-                        _ => {}
-                    }
-                }
-                Err(_) => {}
-            }
-            match title_result {
-                Ok(title) => {
-                    let (title, res) = title;
-                    match res {
-                        CollectionResult::Title => {
-                            scores.insert(title.clone(), scores.get(&title).unwrap() + 1);
-                        }
-                        _ => {}
-                    }
-                }
-                Err(_) => {}
+            for player in players.iter() {
+                scores.entry(player.clone()).or_insert(start_score);
             }
-            handler.stop();
-            round_counter += 1;
-
-            let trackmsg = MessageBuilder::new()
-                .push_bold_line(&track.song_name)
-                .push_bold_line(&track.artist_name)
-                .push_line(&track.get_url())
-                .build();
+            // Store number of participants for skip command
+            running_quiz.write().await.player_count = players.len();
+        }
+        let spectating = spectators.read().await.clone();
 
-            channel.say(&ctx, trackmsg).await.unwrap();
+        let (status, status_updates) = watch::channel(RoundStatus::default());
+        let board = StatusBoard::new(
+            round_title,
+            round_length,
+            points,
+            scores.clone(),
+            &config,
+            &track,
+        );
+        if let Err(why) = start_status_message(ctx, channel, board, status_updates).await {
+            info!("Err sending round status: {:?}", why);
+        }
+        // The call stays unlocked during the round so effects can be layered on top
+        let track_handle = {
+            let mut handler = handler_lock.lock().await;
+            handler.play_source(source)
+        };
+        let playback = watch_playback(&track_handle);
+        if !start_offset.is_zero() {
+            let _ = track_handle.seek_time(start_offset);
+        }
+        {
+            let mut quiz = running_quiz.write().await;
+            quiz.track = Some(track_handle.clone());
+            quiz.gain = loudness_gain(loudness);
+            quiz.apply_volume();
+            // The host may have paused while the round was being set up
+            if quiz.is_paused() {
+                let _ = track_handle.pause();
+            }
+        }
+        info!("Playing: {} by {}", track.song_name, track.artist_name);
+        let effects = sound_effects
+            .as_ref()
+            .map(|effects| EffectPlayer::new(effects.clone(), handler_lock.clone()));
+        let (artist_result, title_result, spectator_finds) = {
+            let res = get_winners(
+                &ctx,
+                interaction.channel_id.clone(),
+                track.clone(),
+                players.clone(),
+                spectating,
+                effects.clone(),
+                RoundSignals {
+                    playback,
+                    connection: connection.clone(),
+                    paused: paused.clone(),
+                    length: round_length,
+                    answers: config.answers,
+                    thresholds: config.thresholds,
+                    status,
+                },
+            )
+            .await;
+            (res.0, res.1, res.2)
+        };
+        // A round cut short by a lost connection doesn't count, the next one replaces it
+        if !*connection.borrow() {
+            let _ = track_handle.stop();
+            running_quiz.write().await.track = None;
+            continue;
+        }
+        rounds.push(Round::new(
+            round_counter as i64,
+            track.id,
+            start_offset.as_millis() as i64,
+            config.answers.artist() as i64 + config.answers.title() as i64,
+        ));
+        for guess in [&artist_result, &title_result].into_iter().flatten() {
+            if guess.result != CollectionResult::Skip {
+                guesses.push(RoundGuess::new(
+                    round_counter as i64,
+                    guess.user.id.0 as i64,
+                    guess.result as i64,
+                    guess.elapsed.as_millis() as i64,
+                ));
+            }
+        }
+        if let Some(effects) = &effects {
+            if (config.answers.artist() && artist_result.is_err())
+                || (config.answers.title() && title_result.is_err())
+            {
+                effects.play(Effect::Buzzer).await;
+            }
+        }
+        let mut round_points = HashMap::<User, u32>::new();
+        for (user, elapsed) in found_by(&artist_result)
+            .into_iter()
+            .chain(found_by(&title_result))
+        {
+            *round_points.entry(user).or_insert(0) +=
+                config.scoring.points(points, elapsed, round_length);
+        }
+        for (user, gained) in &round_points {
+            scores.insert(user.clone(), scores.get(user).unwrap() + gained);
         }
+        round_history.push(round_points);
+        for (spectator, part) in spectator_finds {
+            if config.answers.asks(part) {
+                *spectator_scores.entry(spectator).or_insert(0) += points;
+            }
+        }
+        let _ = track_handle.stop();
+        running_quiz.write().await.track = None;
+
+        check_msg(
+            channel
+                .send_message(&ctx, |m| {
+                    m.set_embed(create_round_embed(
+                        round_counter,
+                        &track,
+                        config.answers,
+                        &artist_result,
+                        &title_result,
+                        language,
+                    ))
+                    .components(|c| {
+                        c.create_action_row(|row| {
+                            row.add_button(create_report_button(track.id, language));
+                            row
+                        })
+                    })
+                })
+                .await,
+        );
+        round_counter += 1;
     }
+    late_join_handle.abort();
     let mut score_message = MessageBuilder::new();
-    score_message.push_bold_line("The Quiz is over! Here are the results:");
+    score_message.push_bold_line(language.text("quiz-results"));
     let mut participants_vec: Vec<_> = scores.iter().collect();
     participants_vec.sort_by(|a, b| b.1.cmp(a.1));
     for (user, score) in participants_vec {
         score_message.push_bold_line(&format!("{}: {}", user.to_string(), score));
     }
+    if !spectator_scores.is_empty() {
+        score_message.push_line(language.text("quiz-spectator-results"));
+        let mut spectators_vec: Vec<_> = spectator_scores.iter().collect();
+        spectators_vec.sort_by(|a, b| b.1.cmp(a.1));
+        for (user, score) in spectators_vec {
+            score_message.push_line(&format!("{}: {}", user.to_string(), score));
+        }
+    }
     let message_string = score_message.build();
-    check_msg(channel.say(&ctx.http, &message_string).await);
-    leave_channel(&ctx, &interaction).await.unwrap();
-    let game = Game::new(0, selected_playlist, quiz_length as i64, start_time);
+    let podium = render_results(&scores, &round_history, language).await;
+    check_msg(
+        channel
+            .send_message(&ctx.http, |m| {
+                m.content(&message_string);
+                if let Some(podium) = podium {
+                    m.add_file(AttachmentType::Bytes {
+                        data: Cow::from(podium),
+                        filename: "results.png".to_string(),
+                    });
+                }
+                m
+            })
+            .await,
+    );
+    running_quizzes.write().await.remove(&guild_id);
+    // A jingle can't be played on a dead connection, so just drop the call
+    if *connection.borrow() {
+        leave_channel(&ctx, &interaction).await.unwrap();
+    } else if manager.get(guild_id).is_some() {
+        let _ = manager.remove(guild_id).await;
+    }
+    let game = Game::new(
+        0,
+        selected_playlist,
+        config.quiz_length as i64,
+        start_time,
+        guild_id.0 as i64,
+        config.answers.name().to_string(),
+    );
     let mut score_vec = Vec::new();
     for (user, score) in scores {
         score_vec.push(Score::new(user.id.0 as i64, 0, score as i64));
     }
-    insert_game(&database, &game, &score_vec).await.unwrap();
+    insert_game(&database, &game, &score_vec, &rounds, &guesses)
+        .await
+        .unwrap();
 }
 
 fn is_title_correct(guess: &str, track: &String, threshold: usize) -> bool {
@@ -723,7 +1444,7 @@ fn is_artist_correct(guess: &str, track: &Song, threshold: usize) -> bool {
 async fn join_channel(
     ctx: &Context,
     interaction: &ApplicationCommandInteraction,
-) -> Result<(), ()> {
+) -> Result<ChannelId, ()> {
     let author_id = interaction.user.id;
     let guild = match interaction.guild_id.unwrap().to_guild_cached(&ctx.cache) {
         Some(it) => it,
@@ -733,6 +1454,8 @@ async fn join_channel(
         .voice_states
         .get(&author_id)
         .and_then(|voice_state| voice_state.channel_id);
+    let settings = read_settings(ctx, guild.id).await;
+    let language = settings.language();
 
     let connect_to = match channel_id {
         Some(channel) => channel,
@@ -741,7 +1464,7 @@ async fn join_channel(
                 .create_interaction_response(ctx, |f| {
                     f.kind(InteractionResponseType::ChannelMessageWithSource)
                         .interaction_response_data(|m| {
-                            m.content("You must be in a voice channel to use this command")
+                            m.content(language.text("voice-channel-required"))
                         })
                 })
                 .await
@@ -757,10 +1480,17 @@ async fn join_channel(
 
     let _handler = manager.join(guild.id, connect_to).await;
 
+    if !settings.jingles_enabled {
+        return Ok(connect_to);
+    }
+    let jingle = settings
+        .join_jingle
+        .unwrap_or_else(|| DEFAULT_JOIN_JINGLE.to_string());
+
     if let Some(handler_lock) = manager.get(guild.id) {
         let mut handler = handler_lock.lock().await;
 
-        let source = match songbird::ytdl("youtube.com/watch?v=MFw3E6X5aoA").await {
+        let source = match load_jingle(&jingle).await {
             Ok(source) => source,
             Err(why) => {
                 info!("Err starting source: {:?}", why);
@@ -768,16 +1498,68 @@ async fn join_channel(
                 check_msg(
                     interaction
                         .channel_id
-                        .say(&ctx.http, "Error sourcing ffmpeg")
+                        .say(
+                            &ctx.http,
+                            language.text_with(
+                                "jingle-join-failed",
+                                &[("error", why.to_string().into())],
+                            ),
+                        )
                         .await,
                 );
-                return Ok(());
+                return Ok(connect_to);
             }
         };
         handler.play_source(source).set_volume(0.7).unwrap();
     }
 
-    return Ok(());
+    return Ok(connect_to);
+}
+
+// Pauses the quiz until the voice connection is back. Gives up after a while,
+// so the quiz can still end normally and keep its scores.
+async fn await_reconnect(
+    ctx: &Context,
+    manager: &Arc<Songbird>,
+    guild_id: GuildId,
+    voice_channel: ChannelId,
+    channel: ChannelId,
+    language: Language,
+) -> Option<(Arc<Mutex<Call>>, watch::Receiver<bool>)> {
+    check_msg(
+        channel
+            .say(&ctx.http, language.text("voice-reconnecting"))
+            .await,
+    );
+    let reconnect = async {
+        loop {
+            let (handler_lock, result) = manager.join(guild_id, voice_channel).await;
+            match result {
+                Ok(_) => return handler_lock,
+                Err(why) => info!("Err reconnecting to voice: {:?}", why),
+            }
+            tokio::time::sleep(RECONNECT_RETRY).await;
+        }
+    };
+    match tokio::time::timeout(RECONNECT_TIMEOUT, reconnect).await {
+        Ok(handler_lock) => {
+            let connection = watch_connection(&handler_lock).await;
+            check_msg(
+                channel
+                    .say(&ctx.http, language.text("voice-reconnected"))
+                    .await,
+            );
+            Some((handler_lock, connection))
+        }
+        Err(_) => {
+            check_msg(
+                channel
+                    .say(&ctx.http, language.text("voice-reconnect-failed"))
+                    .await,
+            );
+            None
+        }
+    }
 }
 
 struct SongEndNotifier {
@@ -816,10 +1598,24 @@ async fn leave_channel(
         .expect("Songbird Voice client placed in at initialisation.")
         .clone();
 
+    let settings = read_settings(ctx, guild.id).await;
+    let language = settings.language();
+    let jingle = settings
+        .leave_jingle
+        .unwrap_or_else(|| DEFAULT_LEAVE_JINGLE.to_string());
+
+    // Without a jingle there is nothing to wait for, so leave right away
+    if !settings.jingles_enabled {
+        if manager.get(guild.id).is_some() {
+            manager.remove(guild.id).await.unwrap();
+        }
+        return Ok(());
+    }
+
     if let Some(handler_lock) = manager.get(guild.id) {
         let mut handler = handler_lock.lock().await;
 
-        let source = match songbird::ytdl("https://www.youtube.com/watch?v=dAqLGeXPKz4").await {
+        let source = match load_jingle(&jingle).await {
             Ok(source) => source,
             Err(why) => {
                 info!("Err starting source: {:?}", why);
@@ -827,9 +1623,17 @@ async fn leave_channel(
                 check_msg(
                     interaction
                         .channel_id
-                        .say(&ctx.http, "Error sourcing ffmpeg")
+                        .say(
+                            &ctx.http,
+                            language.text_with(
+                                "jingle-leave-failed",
+                                &[("error", why.to_string().into())],
+                            ),
+                        )
                         .await,
                 );
+                drop(handler);
+                manager.remove(guild.id).await.unwrap();
                 return Ok(());
             }
         };
@@ -847,3 +1651,14 @@ async fn leave_channel(
 
     Ok(())
 }
+
+async fn read_settings(ctx: &Context, guild_id: GuildId) -> GuildSettings {
+    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
+    match read_guild_settings(&database, guild_id.0 as i64).await {
+        Ok(settings) => settings,
+        Err(why) => {
+            info!("Failed to read guild settings: {:?}", why);
+            GuildSettings::new(guild_id.0 as i64)
+        }
+    }
+}
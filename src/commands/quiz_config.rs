@@ -0,0 +1,464 @@
+use std::{ops::RangeInclusive, time::Duration};
+
+use serenity::model::prelude::interaction::application_command::CommandDataOption;
+
+use crate::{
+    audio::distortion::DistortionMode,
+    commands::lobby::{LateJoin, DEFAULT_MIN_PLAYERS},
+    database::guild_settings::GuildSettings,
+    structs::CollectionResult,
+    util::{
+        locale::Language,
+        util::{get_bool_option, get_int_option, get_string_option},
+    },
+};
+
+pub const DEFAULT_ROUND_DURATION: u64 = 29;
+pub const DEFAULT_THRESHOLD: usize = 3;
+pub const QUIZ_LENGTHS: RangeInclusive<i64> = 1..=100;
+pub const ROUND_DURATIONS: RangeInclusive<i64> = 5..=120;
+pub const LOBBY_DURATIONS: RangeInclusive<i64> = 5..=300;
+pub const PLAYER_COUNTS: RangeInclusive<i64> = 1..=25;
+pub const HINT_COUNTS: RangeInclusive<i64> = 0..=3;
+pub const THRESHOLDS: RangeInclusive<i64> = 0..=10;
+
+// Presets for the other options, anything set explicitly wins over them
+#[derive(Clone, Copy, PartialEq)]
+pub enum QuizMode {
+    Classic,
+    // Long rounds with hints
+    Casual,
+    // Short rounds, fast guesses score more
+    Lightning,
+    // Short rounds starting anywhere in the song
+    Hardcore,
+}
+
+impl QuizMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "classic" => Some(QuizMode::Classic),
+            "casual" => Some(QuizMode::Casual),
+            "lightning" => Some(QuizMode::Lightning),
+            "hardcore" => Some(QuizMode::Hardcore),
+            _ => None,
+        }
+    }
+
+    // Classic quizzes use the round duration of the guild
+    fn round_duration(&self) -> Option<i64> {
+        match self {
+            QuizMode::Classic => None,
+            QuizMode::Casual => Some(45),
+            QuizMode::Lightning => Some(10),
+            QuizMode::Hardcore => Some(20),
+        }
+    }
+
+    fn scoring(&self) -> ScoringScheme {
+        match self {
+            QuizMode::Lightning => ScoringScheme::Speed,
+            _ => ScoringScheme::Fixed,
+        }
+    }
+
+    fn hints(&self) -> u32 {
+        match self {
+            QuizMode::Casual => 2,
+            _ => 0,
+        }
+    }
+
+    fn random_start(&self) -> bool {
+        *self == QuizMode::Hardcore
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ScoringScheme {
+    // Every part is worth the same
+    Fixed,
+    // Up to three times the points for finding a part right away
+    Speed,
+}
+
+impl ScoringScheme {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "fixed" => Some(ScoringScheme::Fixed),
+            "speed" => Some(ScoringScheme::Speed),
+            _ => None,
+        }
+    }
+
+    // Points for one found part, multiplier being the points of the round
+    pub fn points(&self, multiplier: u32, elapsed: Duration, round_length: Duration) -> u32 {
+        match self {
+            ScoringScheme::Fixed => multiplier,
+            ScoringScheme::Speed => {
+                let progress = if round_length.is_zero() {
+                    1.0
+                } else {
+                    (elapsed.as_secs_f64() / round_length.as_secs_f64()).min(1.0)
+                };
+                multiplier * (1 + ((1.0 - progress) * 2.0).round() as u32)
+            }
+        }
+    }
+}
+
+// Which parts of a song have to be guessed
+#[derive(Clone, Copy, PartialEq)]
+pub enum AnswerTypes {
+    Artist,
+    Title,
+    Both,
+}
+
+impl AnswerTypes {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "artist" => Some(AnswerTypes::Artist),
+            "title" => Some(AnswerTypes::Title),
+            "both" => Some(AnswerTypes::Both),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnswerTypes::Artist => "artist",
+            AnswerTypes::Title => "title",
+            AnswerTypes::Both => "both",
+        }
+    }
+
+    pub fn artist(&self) -> bool {
+        *self != AnswerTypes::Title
+    }
+
+    pub fn title(&self) -> bool {
+        *self != AnswerTypes::Artist
+    }
+
+    pub fn asks(&self, part: CollectionResult) -> bool {
+        match part {
+            CollectionResult::Artist => self.artist(),
+            CollectionResult::Title => self.title(),
+            _ => false,
+        }
+    }
+}
+
+// Typos allowed per part of the song
+#[derive(Clone, Copy)]
+pub struct Thresholds {
+    pub title: usize,
+    pub artist: usize,
+}
+
+pub struct QuizConfig {
+    pub quiz_length: u32,
+    // Id or name of the playlist, the host picks one from a menu if it's missing
+    pub playlist: Option<String>,
+    pub round_duration: Duration,
+    pub lobby_duration: Duration,
+    pub min_players: usize,
+    pub late_join: LateJoin,
+    pub random_start: bool,
+    pub distortion: DistortionMode,
+    pub sound_effects: bool,
+    pub scoring: ScoringScheme,
+    pub answers: AnswerTypes,
+    // Hints shown per round, 0 turns them off
+    pub hints: u32,
+    pub thresholds: Thresholds,
+    pub language: Language,
+}
+
+impl QuizConfig {
+    // Reads and validates the options of the quiz command, the guild settings fill the gaps.
+    // Returns a message for the host if an option is out of range.
+    pub fn from_options(
+        options: &[CommandDataOption],
+        settings: &GuildSettings,
+    ) -> Result<Self, String> {
+        let language = settings.language();
+        let mode = read_choice(options, "mode", QuizMode::from_name, language)?
+            .unwrap_or(QuizMode::Classic);
+        let quiz_length = read_int(options, "quiz_length", QUIZ_LENGTHS, language)?
+            .ok_or_else(|| language.text("quiz-length-missing"))?;
+        let round_duration = read_int(options, "round_duration", ROUND_DURATIONS, language)?
+            .or(mode.round_duration())
+            .unwrap_or(settings.round_duration) as u64;
+        let lobby_duration = read_int(options, "lobby_duration", LOBBY_DURATIONS, language)?
+            .unwrap_or(settings.lobby_duration) as u64;
+        let min_players = read_int(options, "min_players", PLAYER_COUNTS, language)?
+            .map_or(DEFAULT_MIN_PLAYERS, |players| players as usize);
+        let hints = read_int(options, "hints", HINT_COUNTS, language)?
+            .map_or(mode.hints(), |hints| hints as u32);
+        let scoring = read_choice(options, "scoring", ScoringScheme::from_name, language)?
+            .unwrap_or(mode.scoring());
+        let answers = read_choice(options, "answers", AnswerTypes::from_name, language)?
+            .unwrap_or(AnswerTypes::Both);
+        let late_join = read_choice(options, "late_join", LateJoin::from_name, language)?
+            .unwrap_or(LateJoin::Zero);
+        let distortion = read_choice(options, "distortion", DistortionMode::from_name, language)?
+            .unwrap_or(DistortionMode::Off);
+
+        Ok(Self {
+            quiz_length: quiz_length as u32,
+            playlist: get_string_option(options, "playlist").filter(|name| !name.is_empty()),
+            round_duration: Duration::from_secs(round_duration),
+            lobby_duration: Duration::from_secs(lobby_duration),
+            min_players,
+            late_join,
+            random_start: get_bool_option(options, "random_start").unwrap_or(mode.random_start()),
+            distortion,
+            sound_effects: get_bool_option(options, "sound_effects").unwrap_or(false),
+            scoring,
+            answers,
+            hints,
+            thresholds: Thresholds {
+                title: settings.title_threshold as usize,
+                artist: settings.artist_threshold as usize,
+            },
+            language,
+        })
+    }
+}
+
+pub fn read_int(
+    options: &[CommandDataOption],
+    name: &str,
+    range: RangeInclusive<i64>,
+    language: Language,
+) -> Result<Option<i64>, String> {
+    match get_int_option(options, name) {
+        Some(value) if !range.contains(&value) => Err(language.text_with(
+            "option-out-of-range",
+            &[
+                ("option", name.into()),
+                ("min", (*range.start()).into()),
+                ("max", (*range.end()).into()),
+            ],
+        )),
+        value => Ok(value),
+    }
+}
+
+pub fn read_choice<T>(
+    options: &[CommandDataOption],
+    name: &str,
+    from_name: fn(&str) -> Option<T>,
+    language: Language,
+) -> Result<Option<T>, String> {
+    match get_string_option(options, name) {
+        Some(value) => match from_name(&value) {
+            Some(choice) => Ok(Some(choice)),
+            None => Err(language.text_with(
+                "option-invalid-choice",
+                &[("option", name.into()), ("value", value.into())],
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::model::prelude::interaction::application_command::CommandDataOptionValue;
+
+    use super::*;
+
+    const ROUND: Duration = Duration::from_secs(20);
+
+    // Options can't be built directly, so they go through the same deserializer as Discord's
+    fn option(name: &str, value: CommandDataOptionValue) -> CommandDataOption {
+        let kind = match value {
+            CommandDataOptionValue::String(_) => 3,
+            CommandDataOptionValue::Integer(_) => 4,
+            CommandDataOptionValue::Boolean(_) => 5,
+            _ => unreachable!("Only used with strings, integers and booleans"),
+        };
+        let mut option: CommandDataOption =
+            serde_json::from_value(serde_json::json!({ "name": name, "type": kind })).unwrap();
+        option.resolved = Some(value);
+        option
+    }
+
+    fn int(name: &str, value: i64) -> CommandDataOption {
+        option(name, CommandDataOptionValue::Integer(value))
+    }
+
+    fn string(name: &str, value: &str) -> CommandDataOption {
+        option(name, CommandDataOptionValue::String(value.to_string()))
+    }
+
+    fn config(options: &[CommandDataOption]) -> Result<QuizConfig, String> {
+        QuizConfig::from_options(options, &GuildSettings::new(0))
+    }
+
+    #[test]
+    fn fixed_points_ignore_the_time() {
+        assert_eq!(ScoringScheme::Fixed.points(2, Duration::ZERO, ROUND), 2);
+        assert_eq!(ScoringScheme::Fixed.points(2, ROUND, ROUND), 2);
+        assert_eq!(ScoringScheme::Fixed.points(2, ROUND, Duration::ZERO), 2);
+    }
+
+    #[test]
+    fn speed_points_at_the_boundaries() {
+        assert_eq!(ScoringScheme::Speed.points(1, Duration::ZERO, ROUND), 3);
+        assert_eq!(ScoringScheme::Speed.points(1, ROUND, ROUND), 1);
+        assert_eq!(ScoringScheme::Speed.points(1, ROUND * 2, ROUND), 1);
+        assert_eq!(ScoringScheme::Speed.points(2, Duration::ZERO, ROUND), 6);
+    }
+
+    #[test]
+    fn speed_points_round_to_the_nearest_multiplier() {
+        assert_eq!(ScoringScheme::Speed.points(1, ROUND / 4, ROUND), 3);
+        assert_eq!(ScoringScheme::Speed.points(1, ROUND / 2, ROUND), 2);
+        assert_eq!(ScoringScheme::Speed.points(1, ROUND * 3 / 4, ROUND), 2);
+        assert_eq!(ScoringScheme::Speed.points(1, ROUND * 4 / 5, ROUND), 1);
+    }
+
+    #[test]
+    fn speed_points_without_a_round_length() {
+        assert_eq!(
+            ScoringScheme::Speed.points(1, Duration::ZERO, Duration::ZERO),
+            1
+        );
+        assert_eq!(ScoringScheme::Speed.points(1, ROUND, Duration::ZERO), 1);
+    }
+
+    #[test]
+    fn quiz_length_is_required_and_in_range() {
+        assert!(config(&[]).is_err());
+        assert!(config(&[int("quiz_length", *QUIZ_LENGTHS.start() - 1)]).is_err());
+        assert!(config(&[int("quiz_length", *QUIZ_LENGTHS.end() + 1)]).is_err());
+        assert_eq!(
+            config(&[int("quiz_length", *QUIZ_LENGTHS.start())])
+                .unwrap()
+                .quiz_length,
+            1
+        );
+        assert_eq!(
+            config(&[int("quiz_length", *QUIZ_LENGTHS.end())])
+                .unwrap()
+                .quiz_length,
+            100
+        );
+    }
+
+    #[test]
+    fn out_of_range_options_are_rejected() {
+        for (name, range) in [
+            ("round_duration", ROUND_DURATIONS),
+            ("lobby_duration", LOBBY_DURATIONS),
+            ("min_players", PLAYER_COUNTS),
+            ("hints", HINT_COUNTS),
+        ] {
+            for value in [*range.start() - 1, *range.end() + 1] {
+                assert!(
+                    config(&[int("quiz_length", 5), int(name, value)]).is_err(),
+                    "{} = {} was accepted",
+                    name,
+                    value
+                );
+            }
+            for value in [*range.start(), *range.end()] {
+                assert!(config(&[int("quiz_length", 5), int(name, value)]).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_choices_are_rejected() {
+        assert!(config(&[int("quiz_length", 5), string("mode", "turbo")]).is_err());
+        assert!(config(&[int("quiz_length", 5), string("scoring", "double")]).is_err());
+        assert!(config(&[int("quiz_length", 5), string("answers", "album")]).is_err());
+        assert!(config(&[int("quiz_length", 5), string("late_join", "later")]).is_err());
+        assert!(config(&[int("quiz_length", 5), string("distortion", "echo")]).is_err());
+    }
+
+    #[test]
+    fn guild_settings_fill_the_gaps() {
+        let settings = GuildSettings {
+            round_duration: 40,
+            lobby_duration: 20,
+            title_threshold: 1,
+            artist_threshold: 5,
+            ..GuildSettings::new(0)
+        };
+        let config = QuizConfig::from_options(&[int("quiz_length", 5)], &settings).unwrap();
+        assert_eq!(config.round_duration, Duration::from_secs(40));
+        assert_eq!(config.lobby_duration, Duration::from_secs(20));
+        assert_eq!(config.thresholds.title, 1);
+        assert_eq!(config.thresholds.artist, 5);
+        assert!(config.scoring == ScoringScheme::Fixed);
+        assert_eq!(config.hints, 0);
+        assert!(!config.random_start);
+
+        // Explicit options and mode presets both win over the guild settings
+        let explicit = QuizConfig::from_options(
+            &[
+                int("quiz_length", 5),
+                int("round_duration", 30),
+                int("lobby_duration", 60),
+            ],
+            &settings,
+        )
+        .unwrap();
+        assert_eq!(explicit.round_duration, Duration::from_secs(30));
+        assert_eq!(explicit.lobby_duration, Duration::from_secs(60));
+        let lightning = QuizConfig::from_options(
+            &[int("quiz_length", 5), string("mode", "lightning")],
+            &settings,
+        )
+        .unwrap();
+        assert_eq!(lightning.round_duration, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn modes_preset_the_other_options() {
+        let lightning = config(&[int("quiz_length", 5), string("mode", "lightning")]).unwrap();
+        assert_eq!(lightning.round_duration, Duration::from_secs(10));
+        assert!(lightning.scoring == ScoringScheme::Speed);
+
+        let casual = config(&[int("quiz_length", 5), string("mode", "casual")]).unwrap();
+        assert_eq!(casual.round_duration, Duration::from_secs(45));
+        assert_eq!(casual.hints, 2);
+
+        let hardcore = config(&[int("quiz_length", 5), string("mode", "hardcore")]).unwrap();
+        assert!(hardcore.random_start);
+    }
+
+    #[test]
+    fn explicit_options_override_the_mode() {
+        let lightning = config(&[
+            int("quiz_length", 5),
+            string("mode", "lightning"),
+            int("round_duration", 30),
+            string("scoring", "fixed"),
+        ])
+        .unwrap();
+        assert_eq!(lightning.round_duration, Duration::from_secs(30));
+        assert!(lightning.scoring == ScoringScheme::Fixed);
+
+        let casual = config(&[
+            int("quiz_length", 5),
+            string("mode", "casual"),
+            int("hints", 0),
+        ])
+        .unwrap();
+        assert_eq!(casual.hints, 0);
+
+        let hardcore = config(&[
+            int("quiz_length", 5),
+            string("mode", "hardcore"),
+            option("random_start", CommandDataOptionValue::Boolean(false)),
+        ])
+        .unwrap();
+        assert!(!hardcore.random_start);
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use serenity::{
+    builder::CreateButton,
+    model::prelude::{
+        component::ButtonStyle,
+        interaction::{message_component::MessageComponentInteraction, InteractionResponseType},
+    },
+    prelude::Context,
+};
+use tracing::info;
+
+use crate::{
+    database::song::insert_song_report,
+    util::locale::{guild_language, Language},
+    BotDatabase,
+};
+
+pub const REPORT_BUTTON_PREFIX: &str = "report_song:";
+
+pub fn create_report_button(song_id: i64, language: Language) -> CreateButton {
+    let mut b = CreateButton::default();
+    b.custom_id(format!("{}{}", REPORT_BUTTON_PREFIX, song_id));
+    b.label(language.text("report-button"));
+    b.style(ButtonStyle::Secondary);
+    b
+}
+
+pub async fn report_song(ctx: &Context, interaction: Arc<MessageComponentInteraction>) {
+    let song_id = match interaction
+        .data
+        .custom_id
+        .trim_start_matches(REPORT_BUTTON_PREFIX)
+        .parse::<i64>()
+    {
+        Ok(song_id) => song_id,
+        Err(_) => return,
+    };
+    let db = {
+        let data = ctx.data.read().await;
+        data.get::<BotDatabase>().unwrap().clone()
+    };
+
+    let content = match insert_song_report(&db, song_id, interaction.user.id.0 as i64).await {
+        Ok(true) => "report-done",
+        Ok(false) => "report-duplicate",
+        Err(why) => {
+            info!("Err reporting song {}: {:?}", song_id, why);
+            "report-failed"
+        }
+    };
+    let content = guild_language(ctx, interaction.guild_id)
+        .await
+        .text(content);
+    interaction
+        .create_interaction_response(&ctx, |f| {
+            f.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|d| d.ephemeral(true).content(content))
+        })
+        .await
+        .unwrap();
+}
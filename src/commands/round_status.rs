@@ -0,0 +1,318 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use rand::seq::SliceRandom;
+
+use serenity::{
+    builder::{CreateComponents, CreateEmbed},
+    model::{
+        channel::Message,
+        prelude::{component::ButtonStyle, ChannelId, User},
+    },
+    prelude::{Context, SerenityError},
+};
+use tokio::{
+    sync::watch,
+    task::JoinHandle,
+    time::{Instant, MissedTickBehavior},
+};
+use tracing::info;
+
+use crate::{
+    commands::{
+        lobby::LateJoin,
+        quiz_config::{AnswerTypes, QuizConfig, ScoringScheme},
+    },
+    database::song::Song,
+    util::locale::Language,
+};
+
+// Discord allows 5 message edits per 5 seconds, this stays well below that
+const STATUS_UPDATE_INTERVAL: Duration = Duration::from_secs(2);
+const PROGRESS_BAR_LENGTH: u32 = 20;
+const MAX_STANDINGS: usize = 10;
+// The last hint reveals a third of the letters
+const HINT_REVEAL_DIVISOR: usize = 3;
+
+#[derive(Clone, Default)]
+pub enum RoundClock {
+    #[default]
+    Starting,
+    Running(Instant),
+    Paused(Duration),
+    Over,
+}
+
+// Published by the round while it runs, rendered into the status message
+#[derive(Clone, Default)]
+pub struct RoundStatus {
+    pub clock: RoundClock,
+    // Who found the part and how far into the round
+    pub artist: Option<(User, Duration)>,
+    pub title: Option<(User, Duration)>,
+}
+
+// A name with its letters hidden, revealed in random order
+struct Hint {
+    name: Vec<char>,
+    order: Vec<usize>,
+}
+
+impl Hint {
+    fn new(name: &str) -> Self {
+        let name: Vec<char> = name.trim().chars().collect();
+        let mut order: Vec<usize> = (0..name.len())
+            .filter(|i| name[*i].is_alphanumeric())
+            .collect();
+        order.shuffle(&mut rand::thread_rng());
+        Self { name, order }
+    }
+
+    fn render(&self, stage: u32, stages: u32) -> String {
+        let revealed = self.order.len() * stage as usize / (stages as usize * HINT_REVEAL_DIVISOR);
+        let shown: HashSet<&usize> = self.order.iter().take(revealed).collect();
+        let masked = self
+            .name
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                if !c.is_alphanumeric() || shown.contains(&i) {
+                    c.to_string()
+                } else {
+                    "_".to_string()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        format!("`{}`", masked)
+    }
+}
+
+pub struct StatusBoard {
+    title: String,
+    length: Duration,
+    points: u32,
+    scores: HashMap<User, u32>,
+    late_join: bool,
+    scoring: ScoringScheme,
+    answers: AnswerTypes,
+    hints: u32,
+    artist_hint: Hint,
+    title_hint: Hint,
+    language: Language,
+}
+
+impl StatusBoard {
+    pub fn new(
+        title: String,
+        length: Duration,
+        points: u32,
+        scores: HashMap<User, u32>,
+        config: &QuizConfig,
+        song: &Song,
+    ) -> Self {
+        // Only the part of the title players have to type is hinted
+        let song_title = song
+            .song_name
+            .split(['(', '-'])
+            .next()
+            .filter(|title| !title.trim().is_empty())
+            .unwrap_or(&song.song_name);
+        Self {
+            title,
+            length,
+            points,
+            scores,
+            late_join: config.late_join != LateJoin::Off,
+            scoring: config.scoring,
+            answers: config.answers,
+            hints: config.hints,
+            artist_hint: Hint::new(&song.artist_name),
+            title_hint: Hint::new(song_title),
+            language: config.language,
+        }
+    }
+
+    fn render(&self, status: &RoundStatus) -> String {
+        let remaining = match status.clock {
+            RoundClock::Starting => self.length,
+            RoundClock::Running(deadline) => deadline.saturating_duration_since(Instant::now()),
+            RoundClock::Paused(remaining) => remaining,
+            RoundClock::Over => Duration::ZERO,
+        };
+        let seconds_left = (remaining.as_millis() as f64 / 1000.0).ceil() as u64;
+        let clock = match status.clock {
+            RoundClock::Starting => "status-starting",
+            RoundClock::Running(_) => "status-running",
+            RoundClock::Paused(_) => "status-paused",
+            RoundClock::Over => "status-over",
+        };
+        let clock = self
+            .language
+            .text_with(clock, &[("seconds", seconds_left.into())]);
+
+        // Points found this round already count towards the standings
+        let mut scores = self.scores.clone();
+        for (user, elapsed) in status.artist.iter().chain(status.title.iter()) {
+            *scores.entry(user.clone()).or_insert(0) +=
+                self.scoring.points(self.points, *elapsed, self.length);
+        }
+        let mut standings: Vec<_> = scores.into_iter().collect();
+        standings.sort_by_key(|entry| Reverse(entry.1));
+        let standings = standings
+            .iter()
+            .take(MAX_STANDINGS)
+            .enumerate()
+            .map(|(i, (user, score))| format!("#{} {}: {}", i + 1, user, score))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        // Hints come up evenly spread over the round
+        let hint_stage = if self.hints == 0 || self.length.is_zero() {
+            0
+        } else {
+            let progress = 1.0 - remaining.as_secs_f64() / self.length.as_secs_f64();
+            ((progress * (self.hints + 1) as f64) as u32).min(self.hints)
+        };
+        let mut parts = String::new();
+        if self.answers.title() {
+            parts.push_str(&format!(
+                "**{}** {}\n",
+                self.language.text("status-title"),
+                self.describe_part(&status.title, &self.title_hint, hint_stage)
+            ));
+        }
+        if self.answers.artist() {
+            parts.push_str(&format!(
+                "**{}** {}\n",
+                self.language.text("status-artist"),
+                self.describe_part(&status.artist, &self.artist_hint, hint_stage)
+            ));
+        }
+
+        format!(
+            "{} {}\n\n{}\n**{}**\n{}",
+            progress_bar(remaining, self.length),
+            clock,
+            parts,
+            self.language.text("status-standings"),
+            standings
+        )
+    }
+
+    fn describe_part(
+        &self,
+        found_by: &Option<(User, Duration)>,
+        hint: &Hint,
+        stage: u32,
+    ) -> String {
+        match found_by {
+            Some((user, _)) => format!("✅ {}", user),
+            None if stage > 0 => format!("❔ {}", hint.render(stage, self.hints)),
+            None => "❔".to_string(),
+        }
+    }
+
+    fn create_embed(&self, rendered: &str) -> CreateEmbed {
+        let mut e = CreateEmbed::default();
+        e.color(0xff7c1e);
+        e.title(&self.title);
+        e.description(rendered);
+        e
+    }
+
+    fn create_components(&self) -> CreateComponents {
+        let mut c = CreateComponents::default();
+        c.create_action_row(|row| {
+            if self.late_join {
+                row.create_button(|b| {
+                    b.custom_id("late_join_button");
+                    b.label(self.language.text("status-join-late"));
+                    b.style(ButtonStyle::Success)
+                });
+            }
+            row.create_button(|b| {
+                b.custom_id("spectate_button");
+                b.label(self.language.text("status-spectate"));
+                b.style(ButtonStyle::Secondary)
+            })
+        });
+        c
+    }
+}
+
+fn progress_bar(remaining: Duration, total: Duration) -> String {
+    let progress = if total.is_zero() {
+        1.0
+    } else {
+        1.0 - remaining.as_secs_f64() / total.as_secs_f64()
+    };
+    let filled = ((progress * PROGRESS_BAR_LENGTH as f64).round() as u32).min(PROGRESS_BAR_LENGTH);
+    format!(
+        "{}{}",
+        "▰".repeat(filled as usize),
+        "▱".repeat((PROGRESS_BAR_LENGTH - filled) as usize)
+    )
+}
+
+// Sends the status message of a round and keeps it up to date until the round is over
+pub async fn start_status_message(
+    ctx: &Context,
+    channel: ChannelId,
+    board: StatusBoard,
+    updates: watch::Receiver<RoundStatus>,
+) -> Result<JoinHandle<()>, SerenityError> {
+    let rendered = board.render(&updates.borrow());
+    let message = channel
+        .send_message(ctx, |m| {
+            m.set_embed(board.create_embed(&rendered))
+                .set_components(board.create_components())
+        })
+        .await?;
+    Ok(tokio::spawn(update_status_message(
+        ctx.clone(),
+        message,
+        board,
+        updates,
+    )))
+}
+
+async fn update_status_message(
+    ctx: Context,
+    mut message: Message,
+    board: StatusBoard,
+    updates: watch::Receiver<RoundStatus>,
+) {
+    let mut last_rendered = board.render(&updates.borrow());
+    let mut timer = tokio::time::interval(STATUS_UPDATE_INTERVAL);
+    // Edits that got held back by a rate limit are not made up for
+    timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        timer.tick().await;
+        let status = updates.borrow().clone();
+        let finished = matches!(status.clock, RoundClock::Over) || updates.has_changed().is_err();
+        let rendered = board.render(&status);
+        if rendered != last_rendered || finished {
+            if let Err(why) = message
+                .edit(&ctx, |m| {
+                    m.set_embed(board.create_embed(&rendered));
+                    // Joining only goes through the message of the current round
+                    if finished {
+                        m.set_components(CreateComponents::default());
+                    }
+                    m
+                })
+                .await
+            {
+                info!("Err updating round status: {:?}", why);
+            }
+            last_rendered = rendered;
+        }
+        if finished {
+            break;
+        }
+    }
+}
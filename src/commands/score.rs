@@ -1,65 +1,439 @@
-use std::sync::Arc;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use serenity::{
-    builder::{CreateApplicationCommand, CreateEmbed},
+    builder::{CreateApplicationCommand, CreateButton, CreateEmbed},
     model::{
         prelude::{
+            command::CommandOptionType,
             component::ButtonStyle,
             interaction::{
-                application_command::ApplicationCommandInteraction,
-                message_component::MessageComponentInteraction, InteractionResponseType,
+                application_command::{ApplicationCommandInteraction, CommandDataOption},
+                message_component::MessageComponentInteraction,
+                InteractionResponseType,
             },
         },
         user::User,
     },
     prelude::Context,
 };
-use sqlx::types::chrono;
+use sqlx::{
+    types::chrono::{DateTime, Local, NaiveDateTime, Utc},
+    SqlitePool,
+};
+
+use crate::{
+    commands::{
+        quiz::find_playlist,
+        quiz_config::{read_choice, read_int},
+    },
+    database::{
+        game::{
+            read_accuracy_leaderboard, read_average_leaderboard, read_leaderboard,
+            read_win_leaderboard, LeaderboardFilter,
+        },
+        playlist::read_playlist,
+        season::{read_current_season, read_season_standings, Season},
+    },
+    util::{
+        locale::{
+            add_localized_choice, guild_language, localize_command, localize_option, Language,
+        },
+        util::get_string_option,
+    },
+    BotDatabase,
+};
+
+// The refresh button carries the options of its leaderboard, separated by colons
+pub const REFRESH_BUTTON_PREFIX: &str = "refresh";
+const GAME_COUNTS: RangeInclusive<i64> = 1..=1000;
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+const SEASON_NUMBERS: RangeInclusive<i64> = 1..=10000;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Period {
+    Today,
+    Week,
+    Month,
+    // Since an admin started the current season
+    Season,
+    All,
+}
+
+impl Period {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "today" => Some(Period::Today),
+            "week" => Some(Period::Week),
+            "month" => Some(Period::Month),
+            "season" => Some(Period::Season),
+            "all" => Some(Period::All),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Period::Today => "today",
+            Period::Week => "week",
+            Period::Month => "month",
+            Period::Season => "season",
+            Period::All => "all",
+        }
+    }
+
+    // Games are stored with their UTC start time
+    fn since(&self, season: &Season) -> Option<NaiveDateTime> {
+        let now = Utc::now().naive_utc();
+        match self {
+            Period::Today => now.date().and_hms_opt(0, 0, 0),
+            Period::Week => days_ago(7),
+            Period::Month => days_ago(30),
+            Period::Season => season.started_at,
+            Period::All => None,
+        }
+    }
+}
+
+// sqlx only re-exports part of chrono, so whole days are taken off the timestamp
+fn days_ago(days: i64) -> Option<NaiveDateTime> {
+    DateTime::<Utc>::from_timestamp(Utc::now().timestamp() - days * SECONDS_PER_DAY, 0)
+        .map(|time| time.naive_utc())
+}
 
-use crate::{database::game::read_leaderboard, BotDatabase};
+#[derive(Clone, Copy, PartialEq)]
+pub enum Ranking {
+    Total,
+    Average,
+    Wins,
+    Accuracy,
+}
+
+impl Ranking {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "total" => Some(Ranking::Total),
+            "average" => Some(Ranking::Average),
+            "wins" => Some(Ranking::Wins),
+            "accuracy" => Some(Ranking::Accuracy),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ranking::Total => "total",
+            Ranking::Average => "average",
+            Ranking::Wins => "wins",
+            Ranking::Accuracy => "accuracy",
+        }
+    }
+
+    fn format(&self, value: f64) -> String {
+        match self {
+            Ranking::Average => format!("{:.1}", value),
+            Ranking::Accuracy => format!("{:.0}%", value),
+            _ => format!("{:.0}", value),
+        }
+    }
+}
+
+struct LeaderboardQuery {
+    period: Period,
+    ranking: Ranking,
+    playlist_id: Option<i64>,
+    min_games: i64,
+    // A past season, shown from its archived standings
+    season: Option<i64>,
+}
+
+impl LeaderboardQuery {
+    fn to_custom_id(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            REFRESH_BUTTON_PREFIX,
+            self.period.name(),
+            self.ranking.name(),
+            self.playlist_id.map_or(String::new(), |id| id.to_string()),
+            self.min_games,
+            self.season
+                .map_or(String::new(), |season| season.to_string())
+        )
+    }
+
+    // Buttons from before the options existed only hold the prefix
+    fn from_custom_id(custom_id: &str) -> Self {
+        let mut parts = custom_id.split(':').skip(1);
+        Self {
+            period: parts
+                .next()
+                .and_then(Period::from_name)
+                .unwrap_or(Period::All),
+            ranking: parts
+                .next()
+                .and_then(Ranking::from_name)
+                .unwrap_or(Ranking::Total),
+            playlist_id: parts.next().and_then(|id| id.parse().ok()),
+            min_games: parts
+                .next()
+                .and_then(|count| count.parse().ok())
+                .unwrap_or(1),
+            season: parts.next().and_then(|season| season.parse().ok()),
+        }
+    }
+}
 
 pub fn register_score(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
-    command
-        .name("score")
-        .description("Display the Trivia-Scoreboard")
+    localize_command(command.name("score"), "score-description")
+        .create_option(|option| {
+            localize_option(option.name("period"), "score-option-period")
+                .kind(CommandOptionType::String);
+            for period in ["today", "week", "month", "season", "all"] {
+                add_localized_choice(option, &format!("score-period-{}", period), period);
+            }
+            option.required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("ranking"), "score-option-ranking")
+                .kind(CommandOptionType::String);
+            for ranking in ["total", "average", "wins", "accuracy"] {
+                add_localized_choice(option, &format!("score-ranking-{}", ranking), ranking);
+            }
+            option.required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("playlist"), "score-option-playlist")
+                .kind(CommandOptionType::String)
+                .set_autocomplete(true)
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("min_games"), "score-option-min_games")
+                .kind(CommandOptionType::Integer)
+                .min_int_value(*GAME_COUNTS.start())
+                .max_int_value(*GAME_COUNTS.end())
+                .required(false)
+        })
+        .create_option(|option| {
+            localize_option(option.name("season"), "score-option-season")
+                .kind(CommandOptionType::Integer)
+                .min_int_value(*SEASON_NUMBERS.start())
+                .max_int_value(*SEASON_NUMBERS.end())
+                .required(false)
+        })
 }
 
-pub async fn run_score(ctx: &Context, interaction: &ApplicationCommandInteraction) {
-    let db = {
-        let data = ctx.data.read().await;
-        data.get::<BotDatabase>().unwrap().clone()
+async fn read_query(
+    db: &SqlitePool,
+    guild_id: i64,
+    current: &Season,
+    options: &[CommandDataOption],
+    language: Language,
+) -> Result<LeaderboardQuery, String> {
+    let playlist_id = match get_string_option(options, "playlist") {
+        Some(value) => match find_playlist(db, guild_id, &value).await {
+            Some(playlist) => Some(playlist.id),
+            None => {
+                return Err(language.text_with("score-unknown-playlist", &[("name", value.into())]))
+            }
+        },
+        None => None,
     };
-    let scores = read_leaderboard(&db).await.unwrap();
-    let mut user_scores = Vec::<(User, i32)>::new();
+    let ranking =
+        read_choice(options, "ranking", Ranking::from_name, language)?.unwrap_or(Ranking::Total);
+    // Choosing the current season is the same as leaving the option out
+    let season = match read_int(options, "season", SEASON_NUMBERS, language)? {
+        Some(season) if season > current.number => {
+            return Err(language.text_with("score-unknown-season", &[("season", season.into())]))
+        }
+        Some(season) if season < current.number => Some(season),
+        _ => None,
+    };
+    // Archives only hold the points, games and wins of each player
+    if season.is_some() && (playlist_id.is_some() || ranking == Ranking::Accuracy) {
+        return Err(language.text("score-archived-options"));
+    }
+    Ok(LeaderboardQuery {
+        period: read_choice(options, "period", Period::from_name, language)?
+            .unwrap_or(Period::Season),
+        ranking,
+        playlist_id,
+        min_games: read_int(options, "min_games", GAME_COUNTS, language)?.unwrap_or(1),
+        season,
+    })
+}
+
+async fn read_entries(
+    ctx: &Context,
+    db: &SqlitePool,
+    guild_id: i64,
+    current: &Season,
+    query: &LeaderboardQuery,
+) -> Vec<(User, f64)> {
+    let scores = match query.season {
+        Some(season) => read_archived_scores(db, guild_id, season, query).await,
+        None => {
+            let filter = LeaderboardFilter {
+                guild_id,
+                since: query.period.since(current),
+                playlist_id: query.playlist_id,
+                min_games: query.min_games,
+            };
+            match query.ranking {
+                Ranking::Total => read_leaderboard(db, &filter).await,
+                Ranking::Average => read_average_leaderboard(db, &filter).await,
+                Ranking::Wins => read_win_leaderboard(db, &filter).await,
+                Ranking::Accuracy => read_accuracy_leaderboard(db, &filter).await,
+            }
+        }
+    }
+    .unwrap();
+    let mut user_scores = Vec::<(User, f64)>::new();
     for (player_id, score) in scores {
         let user = ctx.http.get_user(player_id).await.unwrap();
         user_scores.push((user, score));
     }
+    user_scores
+}
+
+async fn read_archived_scores(
+    db: &SqlitePool,
+    guild_id: i64,
+    season: i64,
+    query: &LeaderboardQuery,
+) -> Result<Vec<(u64, f64)>, sqlx::Error> {
+    let mut scores: Vec<(u64, f64)> = read_season_standings(db, guild_id, season)
+        .await?
+        .iter()
+        .filter(|standing| standing.games >= query.min_games)
+        .map(|standing| {
+            let value = match query.ranking {
+                Ranking::Average => standing.points as f64 / standing.games as f64,
+                Ranking::Wins => standing.wins as f64,
+                _ => standing.points as f64,
+            };
+            (standing.player_id as u64, value)
+        })
+        .collect();
+    // The standings are sorted by points, keeping that order for equal values
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    Ok(scores)
+}
+
+// Ranking, period and playlist of the leaderboard
+async fn describe_query(
+    db: &SqlitePool,
+    guild_id: i64,
+    current: &Season,
+    query: &LeaderboardQuery,
+    language: Language,
+) -> String {
+    let period = match (query.season, query.period) {
+        (Some(season), _) => {
+            language.text_with("score-season-archived", &[("season", season.into())])
+        }
+        (None, Period::Season) => {
+            language.text_with("score-season-current", &[("season", current.number.into())])
+        }
+        (None, period) => language.text(&format!("score-period-{}", period.name())),
+    };
+    let mut description = language.text_with(
+        "score-summary",
+        &[
+            (
+                "ranking",
+                language
+                    .text(&format!("score-ranking-{}", query.ranking.name()))
+                    .into(),
+            ),
+            ("period", period.into()),
+        ],
+    );
+    if let Some(playlist_id) = query.playlist_id {
+        if let Ok(Some(playlist)) = read_playlist(db, playlist_id, guild_id).await {
+            description.push('\n');
+            description
+                .push_str(&language.text_with("score-playlist", &[("name", playlist.name.into())]));
+        }
+    }
+    description
+}
+
+fn create_leaderboard_embed(
+    entries: Vec<(User, f64)>,
+    ranking: Ranking,
+    description: String,
+    language: Language,
+) -> CreateEmbed {
+    let mut embed = CreateEmbed::default();
+    embed.title(language.text("score-title"));
+    if entries.is_empty() {
+        embed.description(format!(
+            "{}\n\n{}",
+            description,
+            language.text("score-empty")
+        ));
+    } else {
+        embed.description(description);
+    }
+    let mut i = 1;
+    for (user, score) in entries {
+        embed.field(
+            "",
+            format!("**#{} - {} : {}**", i, user, ranking.format(score)),
+            false,
+        );
+        i += 1;
+    }
+    embed
+}
+
+fn create_refresh_button(query: &LeaderboardQuery) -> CreateButton {
+    let mut b = CreateButton::default();
+    b.label("🔄");
+    b.style(ButtonStyle::Primary);
+    b.custom_id(query.to_custom_id());
+    b
+}
+
+pub async fn run_score(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id.0 as i64,
+        None => return,
+    };
+    let db = {
+        let data = ctx.data.read().await;
+        data.get::<BotDatabase>().unwrap().clone()
+    };
+    let language = guild_language(ctx, interaction.guild_id).await;
+    let current = read_current_season(&db, guild_id).await.unwrap();
+    let query = match read_query(&db, guild_id, &current, &interaction.data.options, language).await
+    {
+        Ok(query) => query,
+        Err(why) => {
+            let _ = interaction
+                .create_interaction_response(ctx, |r| {
+                    r.kind(InteractionResponseType::ChannelMessageWithSource)
+                        .interaction_response_data(|d| d.ephemeral(true).content(why))
+                })
+                .await;
+            return;
+        }
+    };
+    let user_scores = read_entries(ctx, &db, guild_id, &current, &query).await;
+    let description = describe_query(&db, guild_id, &current, &query, language).await;
     interaction
         .create_interaction_response(ctx, |f| {
             f.kind(InteractionResponseType::ChannelMessageWithSource)
                 .interaction_response_data(|d| {
-                    d.title("Trivia-Scoreboard");
-                    d.add_embed({
-                        let mut embed = CreateEmbed::default();
-                        embed.title("Trivia-Scoreboard");
-                        embed.description("The current Trivia-Scoreboard");
-                        let mut i = 1;
-                        for (user, score) in user_scores {
-                            embed.field("", format!("**#{} - {} : {}**", i, user, score), false);
-                            i += 1;
-                        }
-                        embed
-                    });
+                    d.title(language.text("score-title"));
+                    d.add_embed(create_leaderboard_embed(
+                        user_scores,
+                        query.ranking,
+                        description,
+                        language,
+                    ));
                     d.components(|c| {
-                        c.create_action_row(|a| {
-                            a.create_button(|b| {
-                                b.label("🔄");
-                                b.style(ButtonStyle::Primary);
-                                b.custom_id("refresh");
-                                b
-                            })
-                        })
+                        c.create_action_row(|a| a.add_button(create_refresh_button(&query)))
                     })
                 })
         })
@@ -68,42 +442,37 @@ pub async fn run_score(ctx: &Context, interaction: &ApplicationCommandInteractio
 }
 
 pub async fn refresh(ctx: &Context, interaction: Arc<MessageComponentInteraction>) {
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id.0 as i64,
+        None => return,
+    };
     let db = {
         let data = ctx.data.read().await;
         data.get::<BotDatabase>().unwrap().clone()
     };
-    let scores = read_leaderboard(&db).await.unwrap();
-    let mut user_scores = Vec::<(User, i32)>::new();
-    for (player_id, score) in scores {
-        let user = ctx.http.get_user(player_id).await.unwrap();
-        user_scores.push((user, score));
-    }
-    let current_time = chrono::Utc::now().with_timezone(&chrono::Local);
+    let query = LeaderboardQuery::from_custom_id(&interaction.data.custom_id);
+    let current = read_current_season(&db, guild_id).await.unwrap();
+    let user_scores = read_entries(ctx, &db, guild_id, &current, &query).await;
+    let current_time = Utc::now().with_timezone(&Local);
     let formatted_time = current_time.format("%d.%m.%Y %H:%M:%S").to_string();
+    let language = guild_language(ctx, interaction.guild_id).await;
+    let description = format!(
+        "**{}**\n{}",
+        formatted_time,
+        describe_query(&db, guild_id, &current, &query, language).await
+    );
     interaction
         .create_interaction_response(&ctx, |f| {
             f.kind(InteractionResponseType::UpdateMessage);
             f.interaction_response_data(|d| {
-                d.set_embed({
-                    let mut embed = CreateEmbed::default();
-                    embed.title("Trivia-Scoreboard");
-                    embed.description(format!("**{}**", formatted_time));
-                    let mut i = 1;
-                    for (user, score) in user_scores {
-                        embed.field("", format!("**#{} - {} : {}**", i, user, score), false);
-                        i += 1;
-                    }
-                    embed
-                });
+                d.set_embed(create_leaderboard_embed(
+                    user_scores,
+                    query.ranking,
+                    description,
+                    language,
+                ));
                 d.components(|c| {
-                    c.create_action_row(|a| {
-                        a.create_button(|b| {
-                            b.label("🔄");
-                            b.style(ButtonStyle::Primary);
-                            b.custom_id("refresh");
-                            b
-                        })
-                    })
+                    c.create_action_row(|a| a.add_button(create_refresh_button(&query)))
                 })
             })
         })
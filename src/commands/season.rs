@@ -0,0 +1,97 @@
+use serenity::{
+    builder::CreateApplicationCommand,
+    model::{
+        prelude::{
+            command::CommandOptionType,
+            interaction::{
+                application_command::ApplicationCommandInteraction, InteractionResponseType,
+            },
+        },
+        Permissions,
+    },
+    prelude::Context,
+};
+use tracing::info;
+
+use crate::{
+    database::season::{read_current_season, start_season},
+    util::locale::{guild_language, localize_command, localize_option},
+    BotDatabase,
+};
+
+pub fn register_season(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("season"), "season-description")
+        .default_member_permissions(Permissions::ADMINISTRATOR)
+        .create_option(|option| {
+            localize_option(option.name("show"), "season-show-description")
+                .kind(CommandOptionType::SubCommand)
+        })
+        .create_option(|option| {
+            localize_option(option.name("start"), "season-start-description")
+                .kind(CommandOptionType::SubCommand)
+        })
+}
+
+pub async fn run_season(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id.0 as i64,
+        None => return,
+    };
+    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
+    let language = guild_language(ctx, interaction.guild_id).await;
+
+    let subcommand = interaction
+        .data
+        .options
+        .get(0)
+        .expect("Expected a subcommand");
+    // Everyone sees the start of a season, the rest is only for the admin
+    let (content, ephemeral) = match subcommand.name.as_str() {
+        "start" => match start_season(&database, guild_id).await {
+            Ok(season) => (
+                language.text_with(
+                    "season-started",
+                    &[
+                        ("season", season.number.into()),
+                        ("previous", (season.number - 1).into()),
+                    ],
+                ),
+                false,
+            ),
+            Err(why) => {
+                info!("Failed to start a new season: {:?}", why);
+                (language.text("season-failed"), true)
+            }
+        },
+        _ => match read_current_season(&database, guild_id).await {
+            Ok(season) => (
+                match season.started_at {
+                    Some(started_at) => language.text_with(
+                        "season-current",
+                        &[
+                            ("season", season.number.into()),
+                            (
+                                "since",
+                                started_at.format("%d.%m.%Y %H:%M").to_string().into(),
+                            ),
+                        ],
+                    ),
+                    None => language.text("season-first"),
+                },
+                true,
+            ),
+            Err(why) => {
+                info!("Failed to read the current season: {:?}", why);
+                (language.text("season-failed"), true)
+            }
+        },
+    };
+
+    interaction
+        .create_interaction_response(ctx, |f| {
+            f.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|m| m.ephemeral(ephemeral).content(content))
+        })
+        .await
+        .unwrap();
+}
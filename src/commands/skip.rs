@@ -1,5 +1,3 @@
-use std::sync::atomic::Ordering;
-
 use serenity::{
     builder::CreateApplicationCommand,
     model::prelude::interaction::{
@@ -8,43 +6,61 @@ use serenity::{
     prelude::Context,
 };
 
-use crate::{BotParticipantCount, BotSkipVotes};
+use crate::{
+    util::locale::{guild_language, localize_command},
+    BotRunningQuizzes,
+};
+
+pub const DEFAULT_SKIP_RATIO: f32 = 0.32;
+// Starts the message that ends the round, whatever the language of the guild
+pub const SKIP_MARKER: &str = "⏭️";
 
 pub fn register_skip(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
-    command.name("skip").description("Skip the current song")
+    localize_command(command.name("skip"), "skip-description")
 }
 
 pub async fn run_skip(ctx: &Context, interaction: &ApplicationCommandInteraction) {
-    let counter_lock = {
+    let quizzes = {
         let data_read = ctx.data.read().await;
         data_read
-            .get::<BotSkipVotes>()
-            .expect("Expected SkipVotes")
+            .get::<BotRunningQuizzes>()
+            .expect("Expected BotRunningQuizzes")
             .clone()
     };
-
-    let participant_lock = {
-        let data_read = ctx.data.read().await;
-        data_read
-            .get::<BotParticipantCount>()
-            .expect("Expected SkipVotes")
-            .clone()
+    let quiz = match interaction.guild_id {
+        Some(guild_id) => quizzes.read().await.get(&guild_id).cloned(),
+        None => None,
     };
-    let count = {
-        let mut counter = counter_lock.write().await;
-        counter.insert(interaction.user.id.0);
-        counter.len()
+    let language = guild_language(ctx, interaction.guild_id).await;
+    // Votes are counted per quiz, so they need one running in this guild
+    let (content, skipping) = match quiz {
+        Some(quiz) => {
+            let mut quiz = quiz.write().await;
+            let skipping = quiz.vote_skip(interaction.user.id);
+            let count = quiz.skip_votes.len();
+            println!("Skip Count: {}", count);
+            (
+                language.text_with("skip-count", &[("count", count.into())]),
+                skipping,
+            )
+        }
+        None => (language.text("no-quiz-running"), false),
     };
-    println!("Skip Count: {}", count);
     interaction
         .create_interaction_response(ctx, |f| {
             f.kind(InteractionResponseType::ChannelMessageWithSource)
-                .interaction_response_data(|m| m.content(format!("Skip count: {}", count)))
+                .interaction_response_data(|m| m.content(content))
         })
         .await
         .unwrap();
-    let participants = participant_lock.load(Ordering::Relaxed);
-    if count as f32 / participants as f32 >= 0.32 {
-        interaction.channel_id.say(ctx, "Skipping!").await.unwrap();
+    if skipping {
+        interaction
+            .channel_id
+            .say(
+                ctx,
+                format!("{} {}", SKIP_MARKER, language.text("skip-skipping")),
+            )
+            .await
+            .unwrap();
     }
 }
@@ -0,0 +1,179 @@
+use serenity::{
+    builder::{CreateApplicationCommand, CreateEmbed},
+    model::{
+        prelude::{
+            command::CommandOptionType,
+            interaction::{
+                application_command::ApplicationCommandInteraction, InteractionResponseType,
+            },
+        },
+        user::User,
+    },
+    prelude::Context,
+};
+use tracing::info;
+
+use crate::{
+    database::stats::{read_player_stats, PlayerStats},
+    util::{
+        locale::{guild_language, localize_command, localize_option, Language},
+        util::get_user_option,
+    },
+    BotDatabase,
+};
+
+const TOP_ARTISTS: i64 = 3;
+
+pub fn register_stats(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("stats"), "stats-description").create_option(|option| {
+        localize_option(option.name("user"), "stats-option-user")
+            .kind(CommandOptionType::User)
+            .required(false)
+    })
+}
+
+fn describe_accuracy(found: i64, asked: i64, language: Language) -> String {
+    if asked == 0 {
+        return language.text("stats-none");
+    }
+    language.text_with(
+        "stats-accuracy-value",
+        &[
+            (
+                "percent",
+                format!("{:.0}", found as f64 * 100.0 / asked as f64).into(),
+            ),
+            ("found", found.into()),
+            ("asked", asked.into()),
+        ],
+    )
+}
+
+fn create_stats_embed(user: &User, stats: PlayerStats, language: Language) -> CreateEmbed {
+    let none = language.text("stats-none");
+    let mut e = CreateEmbed::default();
+    e.color(0xff7c1e);
+    e.title(language.text_with("stats-title", &[("user", user.name.clone().into())]));
+    e.thumbnail(user.face());
+    e.field(language.text("stats-games"), stats.games, true);
+    e.field(language.text("stats-wins"), stats.wins, true);
+    e.field(
+        language.text("stats-total-points"),
+        stats.total_points,
+        true,
+    );
+    e.field(
+        language.text("stats-average-points"),
+        stats
+            .average_points
+            .map_or(none.clone(), |average| format!("{:.1}", average)),
+        true,
+    );
+    e.field(
+        language.text("stats-title-accuracy"),
+        describe_accuracy(stats.titles_found, stats.titles_asked, language),
+        true,
+    );
+    e.field(
+        language.text("stats-artist-accuracy"),
+        describe_accuracy(stats.artists_found, stats.artists_asked, language),
+        true,
+    );
+    e.field(
+        language.text("stats-reaction-time"),
+        stats
+            .reaction_ms
+            .map_or(none.clone(), |ms| format!("{:.1}s", ms / 1000.0)),
+        true,
+    );
+    e.field(
+        language.text("stats-longest-streak"),
+        language.text_with(
+            "stats-streak-value",
+            &[("rounds", stats.longest_streak.into())],
+        ),
+        true,
+    );
+    e.field(
+        language.text("stats-best-playlist"),
+        stats.best_playlist.map_or(none.clone(), |(name, average)| {
+            language.text_with(
+                "stats-playlist-value",
+                &[
+                    ("name", name.into()),
+                    ("average", format!("{:.1}", average).into()),
+                ],
+            )
+        }),
+        false,
+    );
+    let artists = stats
+        .top_artists
+        .iter()
+        .map(|(artist, rounds)| {
+            language.text_with(
+                "stats-artist-value",
+                &[
+                    ("artist", artist.clone().into()),
+                    ("rounds", (*rounds).into()),
+                ],
+            )
+        })
+        .collect::<Vec<_>>();
+    e.field(
+        language.text("stats-top-artists"),
+        if artists.is_empty() {
+            none
+        } else {
+            artists.join("\n")
+        },
+        false,
+    );
+    e
+}
+
+pub async fn run_stats(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id.0 as i64,
+        None => return,
+    };
+    let db = {
+        let data = ctx.data.read().await;
+        data.get::<BotDatabase>().unwrap().clone()
+    };
+    let language = guild_language(ctx, interaction.guild_id).await;
+    let user = get_user_option(&interaction.data.options, "user")
+        .unwrap_or_else(|| interaction.user.clone());
+
+    let stats = match read_player_stats(&db, guild_id, user.id.0 as i64, TOP_ARTISTS).await {
+        Ok(stats) => stats,
+        Err(why) => {
+            info!("Failed to read player stats: {:?}", why);
+            let _ = interaction
+                .create_interaction_response(ctx, |r| {
+                    r.kind(InteractionResponseType::ChannelMessageWithSource)
+                        .interaction_response_data(|d| {
+                            d.ephemeral(true).content(language.text("stats-failed"))
+                        })
+                })
+                .await;
+            return;
+        }
+    };
+    interaction
+        .create_interaction_response(ctx, |f| {
+            f.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|d| {
+                    if stats.games == 0 {
+                        d.content(
+                            language
+                                .text_with("stats-no-games", &[("user", user.to_string().into())]),
+                        )
+                    } else {
+                        d.add_embed(create_stats_embed(&user, stats, language))
+                    }
+                })
+        })
+        .await
+        .unwrap();
+}
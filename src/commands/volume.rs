@@ -0,0 +1,66 @@
+use serenity::{
+    builder::CreateApplicationCommand,
+    model::prelude::{
+        command::CommandOptionType,
+        interaction::{
+            application_command::ApplicationCommandInteraction, InteractionResponseType,
+        },
+    },
+    prelude::Context,
+};
+
+use crate::{
+    util::{
+        locale::{guild_language, localize_command, localize_option},
+        util::get_int_option,
+    },
+    BotRunningQuizzes,
+};
+
+pub fn register_volume(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+    localize_command(command.name("volume"), "volume-description").create_option(|option| {
+        localize_option(option.name("level"), "volume-option-level")
+            .kind(CommandOptionType::Integer)
+            .min_int_value(0)
+            .max_int_value(200)
+            .required(true)
+    })
+}
+
+pub async fn run_volume(ctx: &Context, interaction: &ApplicationCommandInteraction) {
+    let level = get_int_option(&interaction.data.options, "level").unwrap_or(50);
+    let quizzes = {
+        let data_read = ctx.data.read().await;
+        data_read
+            .get::<BotRunningQuizzes>()
+            .expect("Expected BotRunningQuizzes")
+            .clone()
+    };
+    let quiz = match interaction.guild_id {
+        Some(guild_id) => quizzes.read().await.get(&guild_id).cloned(),
+        None => None,
+    };
+
+    let language = guild_language(ctx, interaction.guild_id).await;
+    let content = match quiz {
+        None => language.text("no-quiz-running"),
+        Some(quiz) => {
+            let mut quiz = quiz.write().await;
+            if quiz.host != interaction.user.id {
+                language.text("volume-host-only")
+            } else {
+                quiz.volume = level as f32 / 100.0;
+                quiz.apply_volume();
+                language.text_with("volume-set", &[("level", level.into())])
+            }
+        }
+    };
+
+    interaction
+        .create_interaction_response(ctx, |f| {
+            f.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|m| m.content(content))
+        })
+        .await
+        .unwrap();
+}
@@ -6,6 +6,9 @@ pub struct Game {
     pub playlist_id: i64,
     pub game_length: i64,
     pub started_at: chrono::NaiveDateTime,
+    pub guild_id: i64,
+    // Name of the AnswerTypes of the game
+    pub answers: String,
 }
 impl Game {
     pub fn new(
@@ -13,12 +16,16 @@ impl Game {
         playlist_id: i64,
         game_length: i64,
         started_at: chrono::NaiveDateTime,
+        guild_id: i64,
+        answers: String,
     ) -> Self {
         Self {
             id,
             playlist_id,
             game_length,
             started_at,
+            guild_id,
+            answers,
         }
     }
 }
@@ -39,29 +46,84 @@ impl Score {
     }
 }
 
+#[derive(sqlx::FromRow, Debug)]
+pub struct Round {
+    pub round_number: i64,
+    pub song_id: i64,
+    pub start_offset_ms: i64,
+    // How many parts of the song were asked
+    pub parts: i64,
+}
+
+impl Round {
+    pub fn new(round_number: i64, song_id: i64, start_offset_ms: i64, parts: i64) -> Self {
+        Self {
+            round_number,
+            song_id,
+            start_offset_ms,
+            parts,
+        }
+    }
+}
+
+// A part of a song found by a player
+#[derive(sqlx::FromRow, Debug)]
+pub struct RoundGuess {
+    pub round_number: i64,
+    pub player_id: i64,
+    // CollectionResult::Artist or CollectionResult::Title
+    pub part: i64,
+    pub elapsed_ms: i64,
+}
+
+impl RoundGuess {
+    pub fn new(round_number: i64, player_id: i64, part: i64, elapsed_ms: i64) -> Self {
+        Self {
+            round_number,
+            player_id,
+            part,
+            elapsed_ms,
+        }
+    }
+}
+
+// Which games count for a leaderboard
+pub struct LeaderboardFilter {
+    pub guild_id: i64,
+    pub since: Option<chrono::NaiveDateTime>,
+    pub playlist_id: Option<i64>,
+    // Players with fewer games are left out
+    pub min_games: i64,
+}
+
 pub async fn insert_game(
     pool: &SqlitePool,
     game: &Game,
     scores: &Vec<Score>,
+    rounds: &Vec<Round>,
+    guesses: &Vec<RoundGuess>,
 ) -> Result<(), sqlx::Error> {
     sqlx::query!(
         r#"
-        INSERT INTO games (playlist_id, game_length, started_at)
-        VALUES (?, ?, ?)
+        INSERT INTO games (playlist_id, game_length, started_at, guild_id, answers)
+        VALUES (?, ?, ?, ?, ?)
         "#,
         game.playlist_id,
         game.game_length,
-        game.started_at
+        game.started_at,
+        game.guild_id,
+        game.answers
     )
     .execute(pool)
     .await?;
 
     let game_id = sqlx::query!(
         r#"
-        SELECT id FROM games WHERE playlist_id = ? AND started_at = ?
+        SELECT id FROM games WHERE playlist_id = ? AND started_at = ? AND guild_id = ?
         "#,
         game.playlist_id,
-        game.started_at
+        game.started_at,
+        game.guild_id
     )
     .fetch_one(pool)
     .await?
@@ -80,23 +142,185 @@ pub async fn insert_game(
         .execute(&mut transaction)
         .await?;
     }
+    for round in rounds {
+        sqlx::query!(
+            r#"
+            INSERT INTO rounds (game_id, round_number, song_id, start_offset_ms, parts)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+            game_id,
+            round.round_number,
+            round.song_id,
+            round.start_offset_ms,
+            round.parts
+        )
+        .execute(&mut transaction)
+        .await?;
+    }
+    for guess in guesses {
+        sqlx::query!(
+            r#"
+            INSERT INTO guesses (game_id, round_number, player_id, part, elapsed_ms)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+            game_id,
+            guess.round_number,
+            guess.player_id,
+            guess.part,
+            guess.elapsed_ms
+        )
+        .execute(&mut transaction)
+        .await?;
+    }
     transaction.commit().await.unwrap();
     Ok(())
 }
 
-pub async fn read_leaderboard(pool: &SqlitePool) -> Result<Vec<(u64, i32)>, sqlx::Error> {
+pub async fn read_leaderboard(
+    pool: &SqlitePool,
+    filter: &LeaderboardFilter,
+) -> Result<Vec<(u64, f64)>, sqlx::Error> {
     let leaderboard = sqlx::query!(
-        r#"SELECT player_id, SUM(score) as "total_score!:i32"
+        r#"SELECT scores.player_id, CAST(SUM(scores.score) AS REAL) AS "value!: f64"
     FROM scores
-    GROUP BY player_id
-    ORDER BY "total_score!:i32" DESC;"#,
+    JOIN games ON games.id = scores.game_id
+    WHERE games.guild_id = ?
+        AND (? IS NULL OR games.started_at >= ?)
+        AND (? IS NULL OR games.playlist_id = ?)
+    GROUP BY scores.player_id
+    HAVING COUNT(*) >= ?
+    ORDER BY "value!: f64" DESC;"#,
+        filter.guild_id,
+        filter.since,
+        filter.since,
+        filter.playlist_id,
+        filter.playlist_id,
+        filter.min_games
     )
     .fetch_all(pool)
-    .await
-    .unwrap();
-    let leaderboard_vec = leaderboard
+    .await?;
+    Ok(leaderboard
         .iter()
-        .map(|score| (score.player_id as u64, score.total_score))
-        .collect::<Vec<_>>();
-    Ok(leaderboard_vec)
+        .map(|entry| (entry.player_id as u64, entry.value))
+        .collect())
+}
+
+pub async fn read_average_leaderboard(
+    pool: &SqlitePool,
+    filter: &LeaderboardFilter,
+) -> Result<Vec<(u64, f64)>, sqlx::Error> {
+    let leaderboard = sqlx::query!(
+        r#"SELECT scores.player_id, AVG(scores.score) AS "value!: f64"
+    FROM scores
+    JOIN games ON games.id = scores.game_id
+    WHERE games.guild_id = ?
+        AND (? IS NULL OR games.started_at >= ?)
+        AND (? IS NULL OR games.playlist_id = ?)
+    GROUP BY scores.player_id
+    HAVING COUNT(*) >= ?
+    ORDER BY "value!: f64" DESC;"#,
+        filter.guild_id,
+        filter.since,
+        filter.since,
+        filter.playlist_id,
+        filter.playlist_id,
+        filter.min_games
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(leaderboard
+        .iter()
+        .map(|entry| (entry.player_id as u64, entry.value))
+        .collect())
+}
+
+// Games won, everyone with the top score of a game wins it
+pub async fn read_win_leaderboard(
+    pool: &SqlitePool,
+    filter: &LeaderboardFilter,
+) -> Result<Vec<(u64, f64)>, sqlx::Error> {
+    let leaderboard = sqlx::query!(
+        r#"SELECT scores.player_id, CAST(SUM(
+        scores.score > 0 AND scores.score = (
+            SELECT MAX(other.score) FROM scores other WHERE other.game_id = scores.game_id
+        )
+    ) AS REAL) AS "value!: f64"
+    FROM scores
+    JOIN games ON games.id = scores.game_id
+    WHERE games.guild_id = ?
+        AND (? IS NULL OR games.started_at >= ?)
+        AND (? IS NULL OR games.playlist_id = ?)
+    GROUP BY scores.player_id
+    HAVING COUNT(*) >= ?
+    ORDER BY "value!: f64" DESC;"#,
+        filter.guild_id,
+        filter.since,
+        filter.since,
+        filter.playlist_id,
+        filter.playlist_id,
+        filter.min_games
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(leaderboard
+        .iter()
+        .map(|entry| (entry.player_id as u64, entry.value))
+        .collect())
+}
+
+#[derive(sqlx::FromRow)]
+struct LeaderboardEntry {
+    player_id: i64,
+    value: f64,
+}
+
+// Percentage of the asked parts a player found, only games with stored guesses count.
+// Checked at runtime, the compile time analysis of sqlx runs out of memory on this query
+pub async fn read_accuracy_leaderboard(
+    pool: &SqlitePool,
+    filter: &LeaderboardFilter,
+) -> Result<Vec<(u64, f64)>, sqlx::Error> {
+    let leaderboard = sqlx::query_as::<_, LeaderboardEntry>(
+        r#"WITH asked AS (
+        SELECT game_id, SUM(parts) AS parts FROM rounds WHERE parts IS NOT NULL GROUP BY game_id
+    ), found AS (
+        SELECT game_id, player_id, COUNT(*) AS parts FROM guesses GROUP BY game_id, player_id
+    )
+    SELECT scores.player_id, 100.0 * SUM(COALESCE(found.parts, 0)) / SUM(asked.parts) AS value
+    FROM scores
+    JOIN games ON games.id = scores.game_id
+    JOIN asked ON asked.game_id = scores.game_id
+    LEFT JOIN found ON found.game_id = scores.game_id AND found.player_id = scores.player_id
+    WHERE games.guild_id = ?
+        AND (? IS NULL OR games.started_at >= ?)
+        AND (? IS NULL OR games.playlist_id = ?)
+    GROUP BY scores.player_id
+    HAVING COUNT(*) >= ? AND SUM(asked.parts) > 0
+    ORDER BY value DESC;"#,
+    )
+    .bind(filter.guild_id)
+    .bind(filter.since)
+    .bind(filter.since)
+    .bind(filter.playlist_id)
+    .bind(filter.playlist_id)
+    .bind(filter.min_games)
+    .fetch_all(pool)
+    .await?;
+    Ok(leaderboard
+        .iter()
+        .map(|entry| (entry.player_id as u64, entry.value))
+        .collect())
+}
+
+// Games from before guilds were tracked were all played in the one guild the bot was limited to
+pub async fn claim_unscoped_games(pool: &SqlitePool, guild_id: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE games SET guild_id = ? WHERE guild_id IS NULL
+        "#,
+        guild_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
 }
@@ -0,0 +1,115 @@
+use sqlx::SqlitePool;
+
+use crate::{
+    commands::{
+        lobby::DEFAULT_LOBBY_DURATION,
+        quiz_config::{DEFAULT_ROUND_DURATION, DEFAULT_THRESHOLD},
+        skip::DEFAULT_SKIP_RATIO,
+    },
+    structs::DEFAULT_VOLUME,
+    util::locale::Language,
+};
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct GuildSettings {
+    pub guild_id: i64,
+    pub join_jingle: Option<String>,
+    pub leave_jingle: Option<String>,
+    pub jingles_enabled: bool,
+    // Defaults for quizzes in this guild, in seconds
+    pub lobby_duration: i64,
+    pub round_duration: i64,
+    // Typos allowed in a guess
+    pub title_threshold: i64,
+    pub artist_threshold: i64,
+    // Share of the players needed to skip a song
+    pub skip_ratio: f64,
+    pub volume: f64,
+    // Code of the language the bot speaks in this guild
+    pub language: String,
+    // Whether other guilds can play the playlists added here
+    pub share_playlists: bool,
+}
+impl GuildSettings {
+    pub fn new(guild_id: i64) -> Self {
+        Self {
+            guild_id,
+            join_jingle: None,
+            leave_jingle: None,
+            jingles_enabled: true,
+            lobby_duration: DEFAULT_LOBBY_DURATION as i64,
+            round_duration: DEFAULT_ROUND_DURATION as i64,
+            title_threshold: DEFAULT_THRESHOLD as i64,
+            artist_threshold: DEFAULT_THRESHOLD as i64,
+            skip_ratio: DEFAULT_SKIP_RATIO as f64,
+            volume: DEFAULT_VOLUME as f64,
+            language: Language::default().code().to_string(),
+            share_playlists: false,
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        Language::from_code(&self.language).unwrap_or_default()
+    }
+}
+
+// Guilds without a stored row get the defaults
+pub async fn read_guild_settings(
+    pool: &SqlitePool,
+    guild_id: i64,
+) -> Result<GuildSettings, sqlx::Error> {
+    let settings = sqlx::query_as!(
+        GuildSettings,
+        r#"
+        SELECT guild_id, join_jingle, leave_jingle, jingles_enabled, lobby_duration,
+            round_duration, title_threshold, artist_threshold, skip_ratio, volume, language,
+            share_playlists
+        FROM guild_settings WHERE guild_id = ?
+        "#,
+        guild_id
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(settings.unwrap_or_else(|| GuildSettings::new(guild_id)))
+}
+
+pub async fn save_guild_settings(
+    pool: &SqlitePool,
+    settings: &GuildSettings,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO guild_settings (guild_id, join_jingle, leave_jingle, jingles_enabled,
+            lobby_duration, round_duration, title_threshold, artist_threshold, skip_ratio, volume,
+            language, share_playlists)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(guild_id) DO UPDATE SET
+            join_jingle = excluded.join_jingle,
+            leave_jingle = excluded.leave_jingle,
+            jingles_enabled = excluded.jingles_enabled,
+            lobby_duration = excluded.lobby_duration,
+            round_duration = excluded.round_duration,
+            title_threshold = excluded.title_threshold,
+            artist_threshold = excluded.artist_threshold,
+            skip_ratio = excluded.skip_ratio,
+            volume = excluded.volume,
+            language = excluded.language,
+            share_playlists = excluded.share_playlists
+        "#,
+        settings.guild_id,
+        settings.join_jingle,
+        settings.leave_jingle,
+        settings.jingles_enabled,
+        settings.lobby_duration,
+        settings.round_duration,
+        settings.title_threshold,
+        settings.artist_threshold,
+        settings.skip_ratio,
+        settings.volume,
+        settings.language,
+        settings.share_playlists
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
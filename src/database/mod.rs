@@ -1,3 +1,6 @@
 pub mod game;
+pub mod guild_settings;
 pub mod playlist;
+pub mod season;
 pub mod song;
+pub mod stats;
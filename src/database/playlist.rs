@@ -31,14 +31,20 @@ impl Playlist {
     }
 }
 
+// Adding a playlist that already exists refreshes it and makes it visible to the guild as well
 pub async fn insert_playlist(
     tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
     playlist: &Playlist,
+    guild_id: i64,
 ) -> Result<(), sqlx::Error> {
     sqlx::query!(
         r#"
         INSERT INTO playlists (spotify_id, name, amount_songs)
         VALUES (?, ?, ?)
+        ON CONFLICT(spotify_id) DO UPDATE SET
+            name = excluded.name,
+            amount_songs = excluded.amount_songs,
+            last_update = CURRENT_TIMESTAMP
         "#,
         playlist.spotify_id,
         playlist.name,
@@ -46,20 +52,35 @@ pub async fn insert_playlist(
     )
     .execute(&mut *tx)
     .await?;
+    sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO playlist_guilds (playlist_id, guild_id)
+        SELECT id, ? FROM playlists WHERE spotify_id = ?
+        "#,
+        guild_id,
+        playlist.spotify_id
+    )
+    .execute(&mut *tx)
+    .await?;
     Ok(())
 }
 
-pub async fn read_playlists(pool: &SqlitePool) -> Result<Vec<Playlist>, sqlx::Error> {
-    let playlists = sqlx::query_as!(
-        Playlist,
+// Playlists from before guilds were tracked were all added in the one guild the bot was limited to
+pub async fn claim_unscoped_playlists(
+    pool: &SqlitePool,
+    guild_id: i64,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
         r#"
-        SELECT id, spotify_id, name, amount_songs, last_update FROM playlists
-        ORDER BY last_update DESC
-        "#
+        INSERT INTO playlist_guilds (playlist_id, guild_id)
+        SELECT id, ? FROM playlists
+        WHERE id NOT IN (SELECT playlist_id FROM playlist_guilds)
+        "#,
+        guild_id
     )
-    .fetch_all(pool)
+    .execute(pool)
     .await?;
-    Ok(playlists)
+    Ok(result.rows_affected())
 }
 
 pub async fn read_playlist_id(
@@ -76,3 +97,110 @@ pub async fn read_playlist_id(
     .await?;
     Ok(playlist_id.id)
 }
+
+// A guild sees the playlists it added and the ones added by guilds that share their playlists.
+// sqlx can't tell the columns are non-null through the subquery, hence the overrides
+pub async fn read_playlist(
+    pool: &SqlitePool,
+    id: i64,
+    guild_id: i64,
+) -> Result<Option<Playlist>, sqlx::Error> {
+    let playlist = sqlx::query_as!(
+        Playlist,
+        r#"
+        SELECT id AS "id!", spotify_id AS "spotify_id!", name AS "name!",
+            amount_songs AS "amount_songs!",
+            last_update AS "last_update!: chrono::NaiveDateTime"
+        FROM playlists
+        WHERE id = ? AND id IN (
+            SELECT playlist_id FROM playlist_guilds
+            WHERE guild_id = ?
+                OR guild_id IN (SELECT guild_id FROM guild_settings WHERE share_playlists)
+        )
+        "#,
+        id,
+        guild_id
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(playlist)
+}
+
+pub async fn count_playlists(pool: &SqlitePool, guild_id: i64) -> Result<i64, sqlx::Error> {
+    let count = sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "count!: i64" FROM playlists
+        WHERE id IN (
+            SELECT playlist_id FROM playlist_guilds
+            WHERE guild_id = ?
+                OR guild_id IN (SELECT guild_id FROM guild_settings WHERE share_playlists)
+        )
+        "#,
+        guild_id
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(count.count)
+}
+
+// sqlx can't tell the columns are non-null with a LIMIT, hence the overrides
+pub async fn read_playlists_page(
+    pool: &SqlitePool,
+    guild_id: i64,
+    offset: i64,
+    limit: i64,
+) -> Result<Vec<Playlist>, sqlx::Error> {
+    let playlists = sqlx::query_as!(
+        Playlist,
+        r#"
+        SELECT id AS "id!", spotify_id AS "spotify_id!", name AS "name!",
+            amount_songs AS "amount_songs!",
+            last_update AS "last_update!: chrono::NaiveDateTime"
+        FROM playlists
+        WHERE id IN (
+            SELECT playlist_id FROM playlist_guilds
+            WHERE guild_id = ?
+                OR guild_id IN (SELECT guild_id FROM guild_settings WHERE share_playlists)
+        )
+        ORDER BY last_update DESC
+        LIMIT ? OFFSET ?
+        "#,
+        guild_id,
+        limit,
+        offset
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(playlists)
+}
+
+// Case insensitive search by name, most recently updated playlists first
+pub async fn search_playlists(
+    pool: &SqlitePool,
+    guild_id: i64,
+    query: &str,
+    limit: i64,
+) -> Result<Vec<Playlist>, sqlx::Error> {
+    let playlists = sqlx::query_as!(
+        Playlist,
+        r#"
+        SELECT id AS "id!", spotify_id AS "spotify_id!", name AS "name!",
+            amount_songs AS "amount_songs!",
+            last_update AS "last_update!: chrono::NaiveDateTime"
+        FROM playlists
+        WHERE name LIKE '%' || ? || '%' AND id IN (
+            SELECT playlist_id FROM playlist_guilds
+            WHERE guild_id = ?
+                OR guild_id IN (SELECT guild_id FROM guild_settings WHERE share_playlists)
+        )
+        ORDER BY last_update DESC
+        LIMIT ?
+        "#,
+        query,
+        guild_id,
+        limit
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(playlists)
+}
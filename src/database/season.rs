@@ -0,0 +1,127 @@
+use sqlx::{types::chrono, SqlitePool};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Season {
+    pub number: i64,
+    // None for the first season, which covers every game before the second one
+    pub started_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct Standing {
+    pub player_id: i64,
+    pub points: i64,
+    pub games: i64,
+    pub wins: i64,
+}
+
+pub async fn read_current_season(pool: &SqlitePool, guild_id: i64) -> Result<Season, sqlx::Error> {
+    let season = sqlx::query!(
+        r#"
+        SELECT number, started_at AS "started_at: chrono::NaiveDateTime"
+        FROM seasons
+        WHERE guild_id = ?
+        ORDER BY number DESC
+        LIMIT 1
+        "#,
+        guild_id
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(match season {
+        Some(season) => Season {
+            number: season.number,
+            started_at: Some(season.started_at),
+        },
+        None => Season {
+            number: 1,
+            started_at: None,
+        },
+    })
+}
+
+// Archives the standings of the current season and starts the next one
+pub async fn start_season(pool: &SqlitePool, guild_id: i64) -> Result<Season, sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+    let current = sqlx::query!(
+        r#"
+        SELECT number, started_at AS "started_at: chrono::NaiveDateTime"
+        FROM seasons
+        WHERE guild_id = ?
+        ORDER BY number DESC
+        LIMIT 1
+        "#,
+        guild_id
+    )
+    .fetch_optional(&mut transaction)
+    .await?;
+    let (number, since) = match current {
+        Some(season) => (season.number, Some(season.started_at)),
+        None => (1, None),
+    };
+
+    sqlx::query!(
+        r#"
+        INSERT INTO season_standings (guild_id, season, player_id, rank, points, games, wins)
+        SELECT ?, ?, scores.player_id,
+            RANK() OVER (ORDER BY SUM(scores.score) DESC),
+            SUM(scores.score),
+            COUNT(*),
+            SUM(
+                scores.score > 0 AND scores.score = (
+                    SELECT MAX(other.score) FROM scores other WHERE other.game_id = scores.game_id
+                )
+            )
+        FROM scores
+        JOIN games ON games.id = scores.game_id
+        WHERE games.guild_id = ?
+            AND (? IS NULL OR games.started_at >= ?)
+        GROUP BY scores.player_id
+        "#,
+        guild_id,
+        number,
+        guild_id,
+        since,
+        since
+    )
+    .execute(&mut transaction)
+    .await?;
+
+    let next = Season {
+        number: number + 1,
+        started_at: Some(chrono::Utc::now().naive_utc()),
+    };
+    sqlx::query!(
+        r#"
+        INSERT INTO seasons (guild_id, number, started_at) VALUES (?, ?, ?)
+        "#,
+        guild_id,
+        next.number,
+        next.started_at
+    )
+    .execute(&mut transaction)
+    .await?;
+    transaction.commit().await?;
+    Ok(next)
+}
+
+// Final standings of an ended season, best first
+pub async fn read_season_standings(
+    pool: &SqlitePool,
+    guild_id: i64,
+    season: i64,
+) -> Result<Vec<Standing>, sqlx::Error> {
+    sqlx::query_as!(
+        Standing,
+        r#"
+        SELECT player_id, points, games, wins
+        FROM season_standings
+        WHERE guild_id = ? AND season = ?
+        ORDER BY rank, player_id
+        "#,
+        guild_id,
+        season
+    )
+    .fetch_all(pool)
+    .await
+}
@@ -1,5 +1,8 @@
 use sqlx::SqlitePool;
 
+// Songs that failed to load this often are left out of future games
+pub const MAX_SONG_FAILURES: i64 = 3;
+
 #[derive(sqlx::FromRow, Debug, Clone)]
 pub struct Song {
     pub id: i64,
@@ -7,6 +10,10 @@ pub struct Song {
     pub song_name: String,
     pub artist_name: String,
     pub preview_url: String,
+    pub loudness: Option<f64>,
+    pub album_name: Option<String>,
+    pub album_cover_url: Option<String>,
+    pub release_year: Option<i64>,
 }
 impl Song {
     pub fn new(
@@ -15,6 +22,7 @@ impl Song {
         song_name: String,
         artist_name: String,
         preview_url: String,
+        loudness: Option<f64>,
     ) -> Self {
         Self {
             id,
@@ -22,8 +30,23 @@ impl Song {
             song_name,
             artist_name,
             preview_url,
+            loudness,
+            album_name: None,
+            album_cover_url: None,
+            release_year: None,
         }
     }
+    pub fn with_album(
+        mut self,
+        album_name: String,
+        album_cover_url: Option<String>,
+        release_year: Option<i64>,
+    ) -> Self {
+        self.album_name = Some(album_name);
+        self.album_cover_url = album_cover_url;
+        self.release_year = release_year;
+        self
+    }
     pub fn get_url(&self) -> String {
         format!(
             "https://open.spotify.com/track/{}",
@@ -36,12 +59,14 @@ pub async fn read_songs(pool: &SqlitePool, playlist_id: i64) -> Result<Vec<Song>
     let songs = sqlx::query_as!(
         Song,
         r#"
-        SELECT songs.id, songs.spotify_id, songs.song_name, songs.artist_name, songs.preview_url
+        SELECT songs.id, songs.spotify_id, songs.song_name, songs.artist_name, songs.preview_url,
+            songs.loudness, songs.album_name, songs.album_cover_url, songs.release_year
         FROM songs
         INNER JOIN playlist_songs ON playlist_songs.song_id = songs.id
-        WHERE playlist_songs.playlist_id = ?
+        WHERE playlist_songs.playlist_id = ? AND songs.failures < ?
         "#,
-        playlist_id
+        playlist_id,
+        MAX_SONG_FAILURES
     )
     .fetch_all(pool)
     .await?;
@@ -57,8 +82,13 @@ pub async fn insert_songs(
         println!("{:?}\n{}", song, playlist_id);
         sqlx::query!(
             r#"
-            INSERT OR IGNORE INTO songs (spotify_id, song_name, artist_name, preview_url)
-            VALUES(?, ?, ?, ?);
+            INSERT INTO songs (spotify_id, song_name, artist_name, preview_url, album_name,
+                album_cover_url, release_year)
+            VALUES(?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(spotify_id) DO UPDATE SET
+                album_name = excluded.album_name,
+                album_cover_url = excluded.album_cover_url,
+                release_year = excluded.release_year;
             INSERT OR IGNORE INTO playlist_songs(playlist_id, song_id)
             VALUES(?, (SELECT id FROM songs WHERE spotify_id = ?));
             "#,
@@ -66,6 +96,9 @@ pub async fn insert_songs(
             song.song_name,
             song.artist_name,
             song.preview_url,
+            song.album_name,
+            song.album_cover_url,
+            song.release_year,
             playlist_id,
             song.spotify_id
         )
@@ -75,3 +108,71 @@ pub async fn insert_songs(
     }
     Ok(())
 }
+
+pub async fn read_songs_without_loudness(
+    pool: &SqlitePool,
+    playlist_id: i64,
+) -> Result<Vec<Song>, sqlx::Error> {
+    let songs = sqlx::query_as!(
+        Song,
+        r#"
+        SELECT songs.id, songs.spotify_id, songs.song_name, songs.artist_name, songs.preview_url,
+            songs.loudness, songs.album_name, songs.album_cover_url, songs.release_year
+        FROM songs
+        INNER JOIN playlist_songs ON playlist_songs.song_id = songs.id
+        WHERE playlist_songs.playlist_id = ? AND songs.loudness IS NULL
+        "#,
+        playlist_id
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(songs)
+}
+
+pub async fn update_song_loudness(
+    pool: &SqlitePool,
+    song_id: i64,
+    loudness: f64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE songs SET loudness = ? WHERE id = ?
+        "#,
+        loudness,
+        song_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn mark_song_failed(pool: &SqlitePool, song_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE songs SET failures = failures + 1 WHERE id = ?
+        "#,
+        song_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// Returns false if the player already reported this song
+pub async fn insert_song_report(
+    pool: &SqlitePool,
+    song_id: i64,
+    player_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO song_reports (song_id, player_id)
+        VALUES (?, ?)
+        "#,
+        song_id,
+        player_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,176 @@
+use sqlx::SqlitePool;
+
+// Guesses store the part as CollectionResult: 1 is the artist, 2 the title
+pub struct PlayerStats {
+    pub games: i64,
+    pub wins: i64,
+    pub total_points: i64,
+    pub average_points: Option<f64>,
+    pub titles_found: i64,
+    pub titles_asked: i64,
+    pub artists_found: i64,
+    pub artists_asked: i64,
+    pub reaction_ms: Option<f64>,
+    // Playlist with the most points per game, with that average
+    pub best_playlist: Option<(String, f64)>,
+    // Artists with the most recognized songs, with the number of rounds
+    pub top_artists: Vec<(String, i64)>,
+    // Most rounds in a row with at least one found part
+    pub longest_streak: i64,
+}
+
+// Everything a player did in the quizzes of one guild
+pub async fn read_player_stats(
+    pool: &SqlitePool,
+    guild_id: i64,
+    player_id: i64,
+    top_artists: i64,
+) -> Result<PlayerStats, sqlx::Error> {
+    let totals = sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "games!: i64",
+            COALESCE(SUM(scores.score), 0) AS "total!: i64",
+            AVG(scores.score) AS "average: f64",
+            COALESCE(SUM(
+                scores.score > 0 AND scores.score = (
+                    SELECT MAX(other.score) FROM scores other WHERE other.game_id = scores.game_id
+                )
+            ), 0) AS "wins!: i64"
+        FROM scores
+        JOIN games ON games.id = scores.game_id
+        WHERE games.guild_id = ? AND scores.player_id = ?
+        "#,
+        guild_id,
+        player_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    // Only games that stored their answer types count towards the accuracy
+    let accuracy = sqlx::query!(
+        r#"
+        SELECT COALESCE(SUM(games.answers IN ('both', 'title')), 0) AS "titles_asked!: i64",
+            COALESCE(SUM(games.answers IN ('both', 'artist')), 0) AS "artists_asked!: i64",
+            (
+                SELECT COUNT(*) FROM guesses
+                JOIN games ON games.id = guesses.game_id
+                WHERE games.guild_id = ? AND games.answers IS NOT NULL
+                    AND guesses.player_id = ? AND guesses.part = 2
+            ) AS "titles_found!: i64",
+            (
+                SELECT COUNT(*) FROM guesses
+                JOIN games ON games.id = guesses.game_id
+                WHERE games.guild_id = ? AND games.answers IS NOT NULL
+                    AND guesses.player_id = ? AND guesses.part = 1
+            ) AS "artists_found!: i64"
+        FROM rounds
+        JOIN games ON games.id = rounds.game_id
+        JOIN scores ON scores.game_id = games.id
+        WHERE games.guild_id = ? AND scores.player_id = ? AND games.answers IS NOT NULL
+        "#,
+        guild_id,
+        player_id,
+        guild_id,
+        player_id,
+        guild_id,
+        player_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let reaction = sqlx::query!(
+        r#"
+        SELECT AVG(guesses.elapsed_ms) AS "reaction_ms: f64"
+        FROM guesses
+        JOIN games ON games.id = guesses.game_id
+        WHERE games.guild_id = ? AND guesses.player_id = ?
+        "#,
+        guild_id,
+        player_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let best_playlist = sqlx::query!(
+        r#"
+        SELECT playlists.name AS "name!", AVG(scores.score) AS "average!: f64"
+        FROM scores
+        JOIN games ON games.id = scores.game_id
+        JOIN playlists ON playlists.id = games.playlist_id
+        WHERE games.guild_id = ? AND scores.player_id = ?
+        GROUP BY playlists.id
+        ORDER BY "average!: f64" DESC
+        LIMIT 1
+        "#,
+        guild_id,
+        player_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let artists = sqlx::query!(
+        r#"
+        SELECT songs.artist_name AS "artist_name!", COUNT(DISTINCT rounds.id) AS "rounds!: i64"
+        FROM guesses
+        JOIN games ON games.id = guesses.game_id
+        JOIN rounds ON rounds.game_id = guesses.game_id
+            AND rounds.round_number = guesses.round_number
+        JOIN songs ON songs.id = rounds.song_id
+        WHERE games.guild_id = ? AND guesses.player_id = ?
+        GROUP BY songs.artist_name
+        ORDER BY "rounds!: i64" DESC, songs.artist_name
+        LIMIT ?
+        "#,
+        guild_id,
+        player_id,
+        top_artists
+    )
+    .fetch_all(pool)
+    .await?;
+
+    // Every round of the player's games in the order they were played
+    let rounds = sqlx::query!(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM guesses
+            WHERE guesses.game_id = rounds.game_id
+                AND guesses.round_number = rounds.round_number
+                AND guesses.player_id = ?
+        ) AS "found!: bool"
+        FROM rounds
+        JOIN games ON games.id = rounds.game_id
+        JOIN scores ON scores.game_id = games.id
+        WHERE games.guild_id = ? AND scores.player_id = ? AND rounds.parts IS NOT NULL
+        ORDER BY games.started_at, rounds.round_number
+        "#,
+        player_id,
+        guild_id,
+        player_id
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut streak = 0;
+    let mut longest_streak = 0;
+    for round in rounds {
+        streak = if round.found { streak + 1 } else { 0 };
+        longest_streak = longest_streak.max(streak);
+    }
+
+    Ok(PlayerStats {
+        games: totals.games,
+        wins: totals.wins,
+        total_points: totals.total,
+        average_points: totals.average,
+        titles_found: accuracy.titles_found,
+        titles_asked: accuracy.titles_asked,
+        artists_found: accuracy.artists_found,
+        artists_asked: accuracy.artists_asked,
+        reaction_ms: reaction.reaction_ms,
+        best_playlist: best_playlist.map(|playlist| (playlist.name, playlist.average)),
+        top_artists: artists
+            .into_iter()
+            .map(|artist| (artist.artist_name, artist.rounds))
+            .collect(),
+        longest_streak,
+    })
+}
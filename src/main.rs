@@ -3,6 +3,7 @@ use serenity::collector::ComponentInteractionCollectorBuilder;
 use serenity::futures::StreamExt;
 use serenity::{
     async_trait,
+    builder::CreateApplicationCommands,
     client::{Client, Context, EventHandler},
     framework::{standard::macros::group, StandardFramework},
     model::{application::interaction::Interaction, gateway::Ready, prelude::*},
@@ -10,16 +11,14 @@ use serenity::{
 };
 use songbird::SerenityInit;
 use sqlx::{Pool, Sqlite};
-use std::{
-    collections::HashSet,
-    env,
-    sync::{atomic::AtomicU8, Arc},
-};
+use std::{collections::HashMap, env, sync::Arc};
+use structs::RunningQuiz;
 extern crate dotenv;
 use dotenv::dotenv;
 
 extern crate edit_distance;
 
+mod audio;
 mod commands;
 mod database;
 mod spotify;
@@ -27,43 +26,97 @@ mod structs;
 pub mod util;
 struct Handler;
 
+fn register_commands(commands: &mut CreateApplicationCommands) -> &mut CreateApplicationCommands {
+    commands.create_application_command(|command| commands::quiz::register_quiz(command));
+    commands.create_application_command(|command| commands::skip::register_skip(command));
+    commands.create_application_command(|command| commands::score::register_score(command));
+    commands.create_application_command(|command| commands::stats::register_stats(command));
+    commands.create_application_command(|command| commands::season::register_season(command));
+    commands.create_application_command(|command| commands::jingle::register_jingle(command));
+    commands.create_application_command(|command| commands::volume::register_volume(command));
+    commands.create_application_command(|command| commands::pause::register_pause(command));
+    commands.create_application_command(|command| commands::pause::register_resume(command));
+    commands.create_application_command(|command| commands::config::register_config(command))
+}
+
 #[async_trait]
 impl EventHandler for Handler {
     async fn ready(&self, ctx: Context, ready: Ready) {
         let ctx1 = Arc::new(ctx.clone());
         println!("{} is connected!", ready.user.name);
-        let guild_id = GuildId(
-            env::var("DISCORD_GUILD_ID")
-                .expect("Expected a DISCORD_GUILD_ID in environment")
-                .parse()
-                .expect("DISCORD_GUILD_ID must be an INTERGER"),
-        );
-        let _commands = GuildId::set_application_commands(&guild_id, &ctx.http, |commands| {
-            commands.create_application_command(|command| commands::quiz::register_quiz(command));
-            commands.create_application_command(|command| commands::skip::register_skip(command));
-            commands.create_application_command(|command| commands::score::register_score(command))
-        })
-        .await;
+        // Global commands take a while to reach every guild,
+        // so a dev guild can be set to get changes right away
+        let registered = match env::var("DISCORD_GUILD_ID") {
+            Ok(guild_id) => {
+                let guild_id = GuildId(
+                    guild_id
+                        .parse()
+                        .expect("DISCORD_GUILD_ID must be an INTERGER"),
+                );
+                guild_id
+                    .set_application_commands(&ctx.http, register_commands)
+                    .await
+            }
+            Err(_) => {
+                command::Command::set_global_application_commands(&ctx.http, register_commands)
+                    .await
+            }
+        };
+        if let Err(why) = registered {
+            println!("Err registering commands: {:?}", why);
+        }
         // Thread to wait for refresh button interactions
         tokio::spawn(async move {
             let mut comp_int = ComponentInteractionCollectorBuilder::new(&*ctx1)
-                .filter(move |i| i.data.custom_id == "refresh")
+                .filter(move |i| {
+                    i.data
+                        .custom_id
+                        .starts_with(commands::score::REFRESH_BUTTON_PREFIX)
+                })
                 .build();
             while let Some(event) = comp_int.next().await {
                 println!("refreshing");
                 commands::score::refresh(&ctx1, event).await;
             }
         });
+        // Thread to wait for song reports from the round results
+        let ctx2 = Arc::new(ctx.clone());
+        tokio::spawn(async move {
+            let mut comp_int = ComponentInteractionCollectorBuilder::new(&*ctx2)
+                .filter(move |i| {
+                    i.data
+                        .custom_id
+                        .starts_with(commands::report::REPORT_BUTTON_PREFIX)
+                })
+                .build();
+            while let Some(event) = comp_int.next().await {
+                commands::report::report_song(&ctx2, event).await;
+            }
+        });
     }
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
         let command = match interaction {
             Interaction::ApplicationCommand(command) => command,
+            Interaction::Autocomplete(autocomplete) => {
+                // Both commands have a playlist option
+                if matches!(autocomplete.data.name.as_str(), "quiz" | "score") {
+                    commands::quiz::autocomplete_playlist(&ctx, &autocomplete).await;
+                }
+                return;
+            }
             _ => return,
         };
         match command.data.name.as_str() {
             "quiz" => commands::quiz::run_quiz(&ctx, &command).await,
             "skip" => commands::skip::run_skip(&ctx, &command).await,
             "score" => commands::score::run_score(&ctx, &command).await,
+            "stats" => commands::stats::run_stats(&ctx, &command).await,
+            "season" => commands::season::run_season(&ctx, &command).await,
+            "jingle" => commands::jingle::run_jingle(&ctx, &command).await,
+            "volume" => commands::volume::run_volume(&ctx, &command).await,
+            "pause" => commands::pause::run_pause(&ctx, &command).await,
+            "resume" => commands::pause::run_resume(&ctx, &command).await,
+            "config" => commands::config::run_config(&ctx, &command).await,
             _ => return,
         };
     }
@@ -82,14 +135,9 @@ impl TypeMapKey for BotDatabase {
     type Value = Pool<Sqlite>;
 }
 
-struct BotSkipVotes;
-impl TypeMapKey for BotSkipVotes {
-    type Value = Arc<RwLock<HashSet<u64>>>;
-}
-
-struct BotParticipantCount;
-impl TypeMapKey for BotParticipantCount {
-    type Value = Arc<AtomicU8>;
+struct BotRunningQuizzes;
+impl TypeMapKey for BotRunningQuizzes {
+    type Value = Arc<RwLock<HashMap<GuildId, Arc<RwLock<RunningQuiz>>>>>;
 }
 
 #[tokio::main]
@@ -118,6 +166,21 @@ async fn main() {
         )
         .await
         .expect("Failed to connect to database");
+    if let Ok(guild_id) = env::var("DISCORD_GUILD_ID") {
+        let guild_id = guild_id
+            .parse()
+            .expect("DISCORD_GUILD_ID must be an INTERGER");
+        match database::game::claim_unscoped_games(&database, guild_id).await {
+            Ok(0) => {}
+            Ok(claimed) => println!("Assigned {} older games to guild {}", claimed, guild_id),
+            Err(why) => println!("Err assigning older games: {:?}", why),
+        }
+        match database::playlist::claim_unscoped_playlists(&database, guild_id).await {
+            Ok(0) => {}
+            Ok(claimed) => println!("Assigned {} older playlists to guild {}", claimed, guild_id),
+            Err(why) => println!("Err assigning older playlists: {:?}", why),
+        }
+    }
 
     let framework = StandardFramework::new()
         .configure(|c| c.prefix("~"))
@@ -139,8 +202,7 @@ async fn main() {
         let mut data = client.data.write().await;
         data.insert::<BotSpotCred>(Arc::new(spotify));
         data.insert::<BotDatabase>(database);
-        data.insert::<BotSkipVotes>(Arc::new(RwLock::new(HashSet::new())));
-        data.insert::<BotParticipantCount>(Arc::new(AtomicU8::new(0)));
+        data.insert::<BotRunningQuizzes>(Arc::new(RwLock::new(HashMap::new())));
     }
 
     tokio::spawn(async move {
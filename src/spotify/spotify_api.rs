@@ -79,13 +79,29 @@ pub async fn get_tracks(
                 .map(|artist| artist.name.to_string())
                 .collect::<Vec<String>>()
                 .join(", ");
-            tracks.push(Song::new(
-                0,
-                full_track.id.unwrap().to_string(),
-                full_track.name,
-                artits_string,
-                full_track.preview_url.unwrap(),
-            ));
+            // Spotify lists the album covers from largest to smallest
+            let album_cover_url = full_track
+                .album
+                .images
+                .first()
+                .map(|image| image.url.clone());
+            let release_year = full_track
+                .album
+                .release_date
+                .as_ref()
+                .and_then(|date| date.get(..4))
+                .and_then(|year| year.parse::<i64>().ok());
+            tracks.push(
+                Song::new(
+                    0,
+                    full_track.id.unwrap().to_string(),
+                    full_track.name,
+                    artits_string,
+                    full_track.preview_url.unwrap(),
+                    None,
+                )
+                .with_album(full_track.album.name, album_cover_url, release_year),
+            );
         }
         offset += limit;
         if pl.next.is_none() {
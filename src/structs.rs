@@ -1,5 +1,72 @@
+use std::collections::HashSet;
+
+use serenity::model::prelude::UserId;
+use songbird::tracks::TrackHandle;
+use tokio::sync::watch;
+
+#[derive(Clone, Copy, PartialEq)]
 pub enum CollectionResult {
     Skip = 0,
     Artist = 1,
     Title = 2,
 }
+
+pub const DEFAULT_VOLUME: f32 = 0.5;
+
+pub struct RunningQuiz {
+    pub host: UserId,
+    pub volume: f32,
+    pub track: Option<TrackHandle>,
+    pub gain: f32,
+    pub paused: watch::Sender<bool>,
+    // Share of the players needed to skip a song
+    pub skip_ratio: f32,
+    // Players who voted to skip the current song
+    pub skip_votes: HashSet<UserId>,
+    pub player_count: usize,
+}
+
+impl RunningQuiz {
+    pub fn new(host: UserId, volume: f32, skip_ratio: f32) -> Self {
+        Self {
+            host,
+            volume,
+            track: None,
+            gain: 1.0,
+            paused: watch::channel(false).0,
+            skip_ratio,
+            skip_votes: HashSet::new(),
+            player_count: 0,
+        }
+    }
+
+    // Applies the host volume on top of the loudness gain of the current track
+    pub fn apply_volume(&self) {
+        if let Some(track) = &self.track {
+            let _ = track.set_volume(self.volume * self.gain);
+        }
+    }
+
+    // Counts the vote of a player, returns whether enough players want to skip
+    pub fn vote_skip(&mut self, player: UserId) -> bool {
+        self.skip_votes.insert(player);
+        self.skip_votes.len() as f32 / self.player_count.max(1) as f32 >= self.skip_ratio
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    // Pauses or resumes the current track and notifies the quiz loop.
+    // Returns false if the quiz already was in that state.
+    pub fn set_paused(&self, paused: bool) -> bool {
+        if self.is_paused() == paused {
+            return false;
+        }
+        if let Some(track) = &self.track {
+            let _ = if paused { track.pause() } else { track.play() };
+        }
+        self.paused.send_replace(paused);
+        true
+    }
+}
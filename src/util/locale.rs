@@ -0,0 +1,159 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource, FluentValue};
+use serenity::{
+    builder::{CreateApplicationCommand, CreateApplicationCommandOption},
+    model::prelude::GuildId,
+    prelude::Context,
+};
+use tracing::info;
+use unic_langid::LanguageIdentifier;
+
+use crate::{database::guild_settings::read_guild_settings, BotDatabase};
+
+const ENGLISH: &str = include_str!("../../locales/en-US/main.ftl");
+const GERMAN: &str = include_str!("../../locales/de/main.ftl");
+
+static BUNDLES: OnceLock<HashMap<Language, FluentBundle<FluentResource>>> = OnceLock::new();
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+pub const LANGUAGES: [Language; 2] = [Language::English, Language::German];
+
+impl Language {
+    pub fn from_code(code: &str) -> Option<Self> {
+        LANGUAGES
+            .into_iter()
+            .find(|language| language.code() == code)
+    }
+
+    // Stored in the guild settings
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+        }
+    }
+
+    // Locale names Discord uses for command localizations
+    fn discord_locale(&self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::German => "de",
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Language::English => ENGLISH,
+            Language::German => GERMAN,
+        }
+    }
+
+    pub fn text(&self, id: &str) -> String {
+        self.text_with(id, &[])
+    }
+
+    // Missing translations fall back to English, missing messages show their id
+    pub fn text_with(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        let bundles = BUNDLES.get_or_init(load_bundles);
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        for language in [*self, Language::English] {
+            let bundle = &bundles[&language];
+            if let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) {
+                let mut errors = Vec::new();
+                let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+                if !errors.is_empty() {
+                    info!("Err formatting {} in {:?}: {:?}", id, language, errors);
+                }
+                return text.to_string();
+            }
+        }
+        info!("Missing translation for {}", id);
+        id.to_string()
+    }
+}
+
+fn load_bundles() -> HashMap<Language, FluentBundle<FluentResource>> {
+    let mut bundles = HashMap::new();
+    for language in LANGUAGES {
+        let id: LanguageIdentifier = language
+            .discord_locale()
+            .parse()
+            .expect("Language identifiers are valid");
+        let resource = FluentResource::try_new(language.source().to_string())
+            .expect("Locale files are valid Fluent");
+        let mut bundle = FluentBundle::new_concurrent(vec![id]);
+        // Isolation marks would end up around mentions and inside code blocks
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(resource)
+            .expect("Locale files don't define a message twice");
+        bundles.insert(language, bundle);
+    }
+    bundles
+}
+
+// The language a guild chose in /config, English if it can't be read
+pub async fn guild_language(ctx: &Context, guild_id: Option<GuildId>) -> Language {
+    let guild_id = match guild_id {
+        Some(guild_id) => guild_id,
+        None => return Language::default(),
+    };
+    let database = { ctx.data.read().await.get::<BotDatabase>().unwrap().clone() };
+    match read_guild_settings(&database, guild_id.0 as i64).await {
+        Ok(settings) => settings.language(),
+        Err(why) => {
+            info!("Failed to read guild settings: {:?}", why);
+            Language::default()
+        }
+    }
+}
+
+// English is what Discord shows by default, the other languages are added as localizations
+pub fn localize_command<'a>(
+    command: &'a mut CreateApplicationCommand,
+    id: &str,
+) -> &'a mut CreateApplicationCommand {
+    command.description(Language::English.text(id));
+    for language in LANGUAGES {
+        if language != Language::English {
+            command.description_localized(language.discord_locale(), language.text(id));
+        }
+    }
+    command
+}
+
+pub fn localize_option<'a>(
+    option: &'a mut CreateApplicationCommandOption,
+    id: &str,
+) -> &'a mut CreateApplicationCommandOption {
+    option.description(Language::English.text(id));
+    for language in LANGUAGES {
+        if language != Language::English {
+            option.description_localized(language.discord_locale(), language.text(id));
+        }
+    }
+    option
+}
+
+pub fn add_localized_choice<'a>(
+    option: &'a mut CreateApplicationCommandOption,
+    id: &str,
+    value: &str,
+) -> &'a mut CreateApplicationCommandOption {
+    let locales: Vec<(&str, String)> = LANGUAGES
+        .into_iter()
+        .filter(|language| *language != Language::English)
+        .map(|language| (language.discord_locale(), language.text(id)))
+        .collect();
+    option.add_string_choice_localized(Language::English.text(id), value, locales)
+}
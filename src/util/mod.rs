@@ -1 +1,3 @@
+pub mod locale;
+pub mod podium;
 pub mod util;
@@ -0,0 +1,284 @@
+use std::{collections::HashMap, io::Cursor, time::Duration};
+
+use ab_glyph::{FontRef, PxScale};
+use futures::future::join_all;
+use image::{
+    imageops::{self, FilterType},
+    ImageError, ImageFormat, Rgba, RgbaImage,
+};
+use imageproc::{
+    drawing::{draw_filled_rect_mut, draw_text_mut, text_size},
+    rect::Rect,
+};
+use serenity::model::user::User;
+use tracing::info;
+
+use crate::util::locale::Language;
+
+const FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+const WIDTH: u32 = 800;
+const PADDING: u32 = 24;
+const PODIUM_HEIGHT: u32 = 340;
+const AVATAR_SIZE: u32 = 96;
+const ROW_HEIGHT: u32 = 36;
+const NAME_LENGTH: usize = 16;
+const HEATMAP_X: u32 = 420;
+const MAX_CELL_WIDTH: u32 = 24;
+// A stalled download leaves the avatar out instead of holding up the results
+const AVATAR_TIMEOUT: Duration = Duration::from_secs(5);
+
+const BACKGROUND: Rgba<u8> = Rgba([0x2b, 0x2d, 0x31, 0xff]);
+const ROW_BACKGROUND: Rgba<u8> = Rgba([0x31, 0x33, 0x38, 0xff]);
+const ACCENT: Rgba<u8> = Rgba([0xff, 0x7c, 0x1e, 0xff]);
+const TEXT: Rgba<u8> = Rgba([0xff, 0xff, 0xff, 0xff]);
+const EMPTY_CELL: Rgba<u8> = Rgba([0x4e, 0x50, 0x58, 0xff]);
+
+// Block heights of the podium, indexed by place
+const BLOCK_HEIGHTS: [u32; 3] = [150, 115, 85];
+// Second place stands left of the winner, third place right
+const BLOCK_COLUMNS: [u32; 3] = [1, 0, 2];
+
+struct PodiumEntry {
+    name: String,
+    avatar: Option<RgbaImage>,
+    score: u32,
+    round_points: Vec<u32>,
+}
+
+// Renders the final standings and the points per round into a PNG.
+// Returns None if anything goes wrong, the results are still sent as text then.
+pub async fn render_results(
+    scores: &HashMap<User, u32>,
+    rounds: &[HashMap<User, u32>],
+    language: Language,
+) -> Option<Vec<u8>> {
+    let mut standings: Vec<_> = scores.iter().collect();
+    standings.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+
+    // Only the podium shows avatars, they are downloaded side by side
+    let mut avatars = join_all(standings.iter().take(3).map(|(user, _)| fetch_avatar(user)))
+        .await
+        .into_iter();
+    let entries: Vec<_> = standings
+        .into_iter()
+        .map(|(user, score)| PodiumEntry {
+            name: user.name.clone(),
+            avatar: avatars.next().flatten(),
+            score: *score,
+            round_points: rounds
+                .iter()
+                .map(|round| round.get(user).copied().unwrap_or(0))
+                .collect(),
+        })
+        .collect();
+
+    match tokio::task::spawn_blocking(move || render_podium(&entries, language)).await {
+        Ok(Ok(png)) => Some(png),
+        Ok(Err(why)) => {
+            info!("Err rendering podium: {:?}", why);
+            None
+        }
+        Err(why) => {
+            info!("Err rendering podium: {:?}", why);
+            None
+        }
+    }
+}
+
+async fn fetch_avatar(user: &User) -> Option<RgbaImage> {
+    // Animated and webp avatars are requested as png so they can be decoded
+    let url = match &user.avatar {
+        Some(hash) => format!(
+            "https://cdn.discordapp.com/avatars/{}/{}.png?size=128",
+            user.id, hash
+        ),
+        None => user.default_avatar_url(),
+    };
+    let bytes = tokio::time::timeout(AVATAR_TIMEOUT, async {
+        let response = reqwest::get(url).await.ok()?.error_for_status().ok()?;
+        response.bytes().await.ok()
+    })
+    .await
+    .ok()??;
+    let avatar = image::load_from_memory(&bytes).ok()?;
+    Some(imageops::resize(
+        &avatar.to_rgba8(),
+        AVATAR_SIZE,
+        AVATAR_SIZE,
+        FilterType::Triangle,
+    ))
+}
+
+fn render_podium(entries: &[PodiumEntry], language: Language) -> Result<Vec<u8>, ImageError> {
+    let font = FontRef::try_from_slice(FONT).expect("The bundled font is valid");
+    let height = PODIUM_HEIGHT + PADDING * 2 + entries.len() as u32 * ROW_HEIGHT;
+    let mut image = RgbaImage::from_pixel(WIDTH, height, BACKGROUND);
+
+    let column_width = (WIDTH - PADDING * 2) / 3;
+    for (place, entry) in entries.iter().take(3).enumerate() {
+        let center = (PADDING + column_width * BLOCK_COLUMNS[place] + column_width / 2) as i32;
+        let block_top = PODIUM_HEIGHT - BLOCK_HEIGHTS[place];
+        draw_filled_rect_mut(
+            &mut image,
+            Rect::at(center - column_width as i32 / 2 + 8, block_top as i32)
+                .of_size(column_width - 16, BLOCK_HEIGHTS[place]),
+            ACCENT,
+        );
+        draw_centered_text(
+            &mut image,
+            &font,
+            48.0,
+            center,
+            block_top as i32 + 8,
+            &format!("{}", place + 1),
+        );
+        draw_centered_text(
+            &mut image,
+            &font,
+            20.0,
+            center,
+            block_top as i32 + 58,
+            &language.text_with("podium-points", &[("points", entry.score.into())]),
+        );
+        draw_centered_text(
+            &mut image,
+            &font,
+            22.0,
+            center,
+            block_top as i32 - 34,
+            &shorten(&entry.name),
+        );
+        if let Some(avatar) = &entry.avatar {
+            let avatar = round_avatar(avatar);
+            imageops::overlay(
+                &mut image,
+                &avatar,
+                (center - AVATAR_SIZE as i32 / 2) as i64,
+                (block_top - AVATAR_SIZE - 40) as i64,
+            );
+        }
+    }
+
+    let rounds = entries
+        .iter()
+        .map(|entry| entry.round_points.len())
+        .max()
+        .unwrap_or(0) as u32;
+    let max_points = entries
+        .iter()
+        .flat_map(|entry| entry.round_points.iter().copied())
+        .max()
+        .unwrap_or(0);
+    let cell_width = (WIDTH - PADDING - HEATMAP_X)
+        .checked_div(rounds)
+        .unwrap_or(0)
+        .min(MAX_CELL_WIDTH);
+
+    for (i, entry) in entries.iter().enumerate() {
+        let y = (PODIUM_HEIGHT + PADDING + i as u32 * ROW_HEIGHT) as i32;
+        draw_filled_rect_mut(
+            &mut image,
+            Rect::at(PADDING as i32, y).of_size(WIDTH - PADDING * 2, ROW_HEIGHT - 4),
+            ROW_BACKGROUND,
+        );
+        let text_y = y + 6;
+        let scale = PxScale::from(20.0);
+        draw_text_mut(
+            &mut image,
+            TEXT,
+            PADDING as i32 + 8,
+            text_y,
+            scale,
+            &font,
+            &format!("#{}", i + 1),
+        );
+        draw_text_mut(
+            &mut image,
+            TEXT,
+            PADDING as i32 + 60,
+            text_y,
+            scale,
+            &font,
+            &shorten(&entry.name),
+        );
+        draw_text_mut(
+            &mut image,
+            ACCENT,
+            PADDING as i32 + 300,
+            text_y,
+            scale,
+            &font,
+            &entry.score.to_string(),
+        );
+
+        // One cell per round, brighter the more points were made
+        for (round, points) in entry.round_points.iter().enumerate() {
+            if cell_width < 3 {
+                break;
+            }
+            let color = if *points == 0 {
+                EMPTY_CELL
+            } else {
+                blend(EMPTY_CELL, ACCENT, *points as f32 / max_points as f32)
+            };
+            draw_filled_rect_mut(
+                &mut image,
+                Rect::at((HEATMAP_X + round as u32 * cell_width) as i32, y + 6)
+                    .of_size(cell_width - 2, ROW_HEIGHT - 16),
+                color,
+            );
+        }
+    }
+
+    let mut png = Vec::new();
+    image.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+    Ok(png)
+}
+
+fn draw_centered_text(
+    image: &mut RgbaImage,
+    font: &FontRef,
+    size: f32,
+    center: i32,
+    y: i32,
+    text: &str,
+) {
+    let scale = PxScale::from(size);
+    let (width, _) = text_size(scale, font, text);
+    draw_text_mut(image, TEXT, center - width as i32 / 2, y, scale, font, text);
+}
+
+fn shorten(name: &str) -> String {
+    if name.chars().count() > NAME_LENGTH {
+        let short: String = name.chars().take(NAME_LENGTH - 1).collect();
+        format!("{}…", short)
+    } else {
+        name.to_string()
+    }
+}
+
+// Cuts the square avatar into a circle
+fn round_avatar(avatar: &RgbaImage) -> RgbaImage {
+    let mut avatar = avatar.clone();
+    let radius = avatar.width() as f32 / 2.0;
+    for (x, y, pixel) in avatar.enumerate_pixels_mut() {
+        let dx = x as f32 + 0.5 - radius;
+        let dy = y as f32 + 0.5 - radius;
+        if dx * dx + dy * dy > radius * radius {
+            pixel.0[3] = 0;
+        }
+    }
+    avatar
+}
+
+fn blend(from: Rgba<u8>, to: Rgba<u8>, amount: f32) -> Rgba<u8> {
+    let amount = amount.clamp(0.0, 1.0);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+    Rgba([
+        mix(from.0[0], to.0[0]),
+        mix(from.0[1], to.0[1]),
+        mix(from.0[2], to.0[2]),
+        0xff,
+    ])
+}
@@ -1,7 +1,49 @@
-use serenity::{model::channel::Message, Result as SerenityResult};
+use serenity::{
+    model::{
+        channel::Message,
+        prelude::interaction::application_command::{CommandDataOption, CommandDataOptionValue},
+        user::User,
+    },
+    Result as SerenityResult,
+};
 
 pub fn check_msg(result: SerenityResult<Message>) {
     if let Err(why) = result {
         println!("Error sending message: {:?}", why);
     }
 }
+
+pub fn get_option<'a>(
+    options: &'a [CommandDataOption],
+    name: &str,
+) -> Option<&'a CommandDataOptionValue> {
+    options.iter().find(|o| o.name == name)?.resolved.as_ref()
+}
+
+pub fn get_string_option(options: &[CommandDataOption], name: &str) -> Option<String> {
+    match get_option(options, name)? {
+        CommandDataOptionValue::String(value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+pub fn get_int_option(options: &[CommandDataOption], name: &str) -> Option<i64> {
+    match get_option(options, name)? {
+        CommandDataOptionValue::Integer(value) => Some(*value),
+        _ => None,
+    }
+}
+
+pub fn get_user_option(options: &[CommandDataOption], name: &str) -> Option<User> {
+    match get_option(options, name)? {
+        CommandDataOptionValue::User(user, _) => Some(user.clone()),
+        _ => None,
+    }
+}
+
+pub fn get_bool_option(options: &[CommandDataOption], name: &str) -> Option<bool> {
+    match get_option(options, name)? {
+        CommandDataOptionValue::Boolean(value) => Some(*value),
+        _ => None,
+    }
+}